@@ -443,7 +443,7 @@ fn handle_menu_change(
     menu_query: Query<&ParentMenu>,
 ) {
     for event in nav_events.read() {
-        if let NavEvent::FocusChanged { to, from } = event {
+        if let NavEvent::FocusChanged { to, from, .. } = event {
             let menu_query = (menu_query.get(*from.first()), menu_query.get(*to.first()));
             if let (Ok(from), Ok(to)) = menu_query {
                 if from.0 != to.0 {
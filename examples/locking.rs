@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 
 use bevy_ui_navigation::prelude::{
-    DefaultNavigationPlugins, FocusState, Focusable, NavEvent, NavRequest, NavRequestSystem,
+    DefaultNavigationPlugins, FocusState, Focusable, Hovered, NavEvent, NavRequest,
+    NavRequestSystem,
 };
 
 /// This example illustrates how to make a button "lock". To lock the UI, press
@@ -41,19 +42,18 @@ fn extra_lock_key(mut requests: EventWriter<NavRequest>, input: Res<Input<KeyCod
     }
 }
 
-#[allow(clippy::type_complexity)]
+// The mouse hovering a button doesn't focus it in this example,
+// so we show it with a distinct color.
 fn button_system(
-    mut interaction_query: Query<
-        (&Focusable, &mut BackgroundColor),
-        (Changed<Focusable>, With<Button>),
-    >,
+    mut interaction_query: Query<(&Focusable, Has<Hovered>, &mut BackgroundColor), With<Button>>,
 ) {
-    for (focus, mut material) in interaction_query.iter_mut() {
-        if let FocusState::Focused = focus.state() {
-            *material = Color::ORANGE_RED.into();
-        } else {
-            *material = Color::DARK_GRAY.into();
-        }
+    for (focus, hovered, mut material) in interaction_query.iter_mut() {
+        let color = match (focus.state(), hovered) {
+            (FocusState::Focused, _) => Color::ORANGE_RED,
+            (_, true) => Color::GRAY,
+            (_, false) => Color::DARK_GRAY,
+        };
+        *material = color.into();
     }
 }
 
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use bevy_ui_navigation::{
+    nav_tree,
+    prelude::{
+        DefaultNavigationPlugins, FocusState, Focusable, MenuSetting, NavEvent, NavEventReaderExt,
+        NavRequestSystem,
+    },
+    systems::InputMapping,
+};
+
+/// This example shows how to declare a menu tree with the `nav_tree!` macro.
+///
+/// The "Options" button leads to the options submenu, declared with
+/// `from options`. `options` is the identifier of the button, so there is
+/// no need to spawn the button first to get its `Entity`, or to name it.
+///
+/// Press `ENTER` on "Options" to enter the submenu, and `BACKSPACE`
+/// or `ENTER` on "Back" to go back to the main menu.
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DefaultNavigationPlugins))
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (button_system, print_activated).after(NavRequestSystem),
+        )
+        .run();
+}
+
+fn button_system(
+    mut interaction_query: Query<(&Focusable, &mut BackgroundColor), Changed<Focusable>>,
+) {
+    for (focus, mut material) in interaction_query.iter_mut() {
+        let color = match focus.state() {
+            FocusState::Focused => Color::ORANGE_RED,
+            FocusState::Active => Color::GOLD,
+            FocusState::Prioritized => Color::GRAY,
+            FocusState::Inert => Color::DARK_GRAY,
+            FocusState::Blocked => Color::ANTIQUE_WHITE,
+        };
+        *material = color.into();
+    }
+}
+
+fn print_activated(mut events: EventReader<NavEvent>, names: Query<&Name>) {
+    for activated in events.nav_iter().activated_in_query(&names) {
+        println!("Activated {activated}");
+    }
+}
+
+fn menu() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            margin: UiRect::all(Val::Px(20.0)),
+            ..default()
+        },
+        background_color: Color::BLACK.into(),
+        ..default()
+    }
+}
+
+fn button(name: &'static str) -> (ButtonBundle, Name) {
+    let bundle = ButtonBundle {
+        style: Style {
+            width: Val::Px(200.0),
+            height: Val::Px(60.0),
+            margin: UiRect::all(Val::Px(10.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        background_color: Color::DARK_GRAY.into(),
+        ..default()
+    };
+    (bundle, Name::new(name))
+}
+
+fn text(text: &str) -> TextBundle {
+    TextBundle::from_section(text, TextStyle::default())
+}
+
+fn setup(mut commands: Commands, mut input_mapping: ResMut<InputMapping>) {
+    input_mapping.keyboard_navigation = true;
+    // ui camera
+    commands.spawn(Camera2dBundle::default());
+
+    nav_tree!(commands,
+        screen: node(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        }) [
+            main: menu(MenuSetting::new().wrapping(), menu()) [
+                play: prioritized(button("Play")) [ play_text: node(text("Play")) ],
+                options: focusable(button("Options")) [ options_text: node(text("Options")) ],
+                quit: focusable(button("Quit")) [ quit_text: node(text("Quit")) ],
+            ],
+            options_menu: menu(MenuSetting::new().wrapping(), menu()) from options [
+                audio: focusable(button("Audio")) [ audio_text: node(text("Audio")) ],
+                video: focusable(button("Video")) [ video_text: node(text("Video")) ],
+                back: focusable(button("Back")) [ back_text: node(text("Back")) ],
+            ],
+        ],
+    );
+    // Identifiers are the spawned entities, to further customize them.
+    commands.entity(back).insert(Focusable::cancel());
+}
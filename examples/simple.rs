@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 
 use bevy_ui_navigation::{
-    prelude::{DefaultNavigationPlugins, FocusState, Focusable, NavEvent, NavRequestSystem},
+    prelude::{DefaultNavigationPlugins, Focusable, NavEvent, NavRequestSystem},
     systems::InputMapping,
 };
 
@@ -30,7 +30,7 @@ fn button_system(
     mut interaction_query: Query<(&Focusable, &mut BackgroundColor), Changed<Focusable>>,
 ) {
     for (focusable, mut material) in interaction_query.iter_mut() {
-        if let FocusState::Focused = focusable.state() {
+        if focusable.is_focused() {
             *material = Color::ORANGE_RED.into();
         } else {
             *material = Color::DARK_GRAY.into();
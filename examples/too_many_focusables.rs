@@ -76,7 +76,7 @@ fn non_stop_move(
             Direction::East => Direction::North,
             Direction::North => Direction::West,
             Direction::West => Direction::South,
-            Direction::South => Direction::East,
+            _ => Direction::East,
         };
         last_direction.0 = new_direction;
     }
@@ -1,6 +1,11 @@
-use bevy::ecs::{entity::Entity, prelude::World, system::Command};
+use bevy::ecs::{
+    entity::Entity,
+    prelude::World,
+    system::{Command, SystemState},
+};
+use bevy::log::warn;
 
-use crate::resolve::{FocusState, Focused};
+use crate::resolve::{FocusState, Focusable, Focused, NavQueries, TreeMenu, DORMANT_MEMORY};
 
 pub(crate) fn set_focus_state(entity: Entity, new_state: FocusState) -> UpdateFocusable {
     UpdateFocusable { entity, new_state }
@@ -19,3 +24,147 @@ impl Command for UpdateFocusable {
         }
     }
 }
+
+/// Re-pick the focusable entered when activating the given menu,
+/// from its current children.
+///
+/// Use this after restructuring the children of a menu at runtime,
+/// for example in dynamic lists, so that it enters the first
+/// [`Focusable::prioritized`] focusable, or its first focusable.
+///
+/// Menus are built at the beginning of the next frame after they are spawned,
+/// this does nothing for menus not built yet. Like [`SetActiveChild`],
+/// this does nothing and logs a warning when `menu` contains the currently
+/// focused entity or its [`FocusState::Active`] parents.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// # #[derive(Component)] struct Inventory;
+/// fn refresh_inventory(mut commands: Commands, inventory: Query<Entity, With<Inventory>>) {
+///     for menu in &inventory {
+///         commands.add(RefreshMenu(menu));
+///     }
+/// }
+/// ```
+///
+/// [`Focusable::prioritized`]: crate::prelude::Focusable::prioritized
+/// [`Focusable`]: crate::prelude::Focusable
+#[derive(Debug, Clone, Copy)]
+pub struct RefreshMenu(pub Entity);
+impl Command for RefreshMenu {
+    fn apply(self, world: &mut World) {
+        let menu = self.0;
+        let mut state = SystemState::<NavQueries>::new(world);
+        let active_child = state.get(world).pick_active_child(menu);
+        let Some(previous) = world.get::<TreeMenu>(menu).map(|tree| tree.active_child) else {
+            return;
+        };
+        let Some(active_child) = active_child else {
+            warn!("Refreshed menu {menu:?} has no focusables");
+            return;
+        };
+        let state_of = |entity| world.get::<Focusable>(entity).map(|f| f.state);
+        if matches!(
+            state_of(previous),
+            Some(FocusState::Active | FocusState::Focused)
+        ) {
+            warn!("Can't refresh menu {menu:?} while it is focused");
+            return;
+        }
+        world.get_mut::<TreeMenu>(menu).unwrap().active_child = active_child;
+        if previous != active_child {
+            swap_prioritized(world, previous, active_child);
+        }
+    }
+}
+
+/// Move the [`FocusState::Prioritized`] state of a menu's `previous`
+/// active child to `child`, leaving other states, such as
+/// [`FocusState::Blocked`], untouched.
+fn swap_prioritized(world: &mut World, previous: Entity, child: Entity) {
+    if let Some(mut focusable) = world.get_mut::<Focusable>(previous) {
+        if focusable.state == FocusState::Prioritized {
+            focusable.state = FocusState::Inert;
+        }
+    }
+    if let Some(mut focusable) = world.get_mut::<Focusable>(child) {
+        if focusable.state == FocusState::Inert {
+            focusable.state = FocusState::Prioritized;
+        }
+    }
+}
+
+/// Set the focusable entered when activating the given menu,
+/// the programmatic counterpart of the menu remembering its last focus.
+///
+/// `child` must be a non-blocked [`Focusable`] of `menu`,
+/// and `menu` must not contain the currently focused entity or its
+/// [`FocusState::Active`] parents, use [`NavRequest::FocusOn`] instead.
+/// Otherwise, this does nothing and logs a warning.
+///
+/// Read the current value with [`ActiveChildren`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// # #[derive(Component)] struct Options;
+/// # #[derive(Component)] struct LastOption;
+/// fn enter_options_at_last(
+///     mut commands: Commands,
+///     options: Query<Entity, With<Options>>,
+///     last: Query<Entity, With<LastOption>>,
+/// ) {
+///     commands.add(SetActiveChild { menu: options.single(), child: last.single() });
+/// }
+/// ```
+///
+/// [`Focusable`]: crate::prelude::Focusable
+/// [`NavRequest::FocusOn`]: crate::prelude::NavRequest::FocusOn
+/// [`ActiveChildren`]: crate::prelude::ActiveChildren
+#[derive(Debug, Clone, Copy)]
+pub struct SetActiveChild {
+    /// The menu entity, with a [`MenuSetting`](crate::prelude::MenuSetting).
+    pub menu: Entity,
+    /// The [`Focusable`](crate::prelude::Focusable) to enter `menu` on.
+    pub child: Entity,
+}
+impl Command for SetActiveChild {
+    fn apply(self, world: &mut World) {
+        let Self { menu, child } = self;
+        let mut state = SystemState::<NavQueries>::new(world);
+        if !state
+            .get(world)
+            .children
+            .focusables_of(menu)
+            .contains(&child)
+        {
+            warn!(
+                "Can't set active child of menu {menu:?}, {child:?} is not one of its focusables"
+            );
+            return;
+        }
+        let Some(previous) = world.get::<TreeMenu>(menu).map(|tree| tree.active_child) else {
+            warn!("Can't set active child of {menu:?}, it is not a menu or not built yet");
+            return;
+        };
+        if previous == child {
+            return;
+        }
+        let state_of = |world: &World, entity| world.get::<Focusable>(entity).map(|f| f.state);
+        let previous_state = state_of(world, previous);
+        if matches!(
+            previous_state,
+            Some(FocusState::Active | FocusState::Focused)
+        ) {
+            warn!("Can't set active child of menu {menu:?} while it is focused");
+            return;
+        }
+        let mut tree = world.get_mut::<TreeMenu>(menu).unwrap();
+        tree.active_child = child;
+        tree.dormant.retain(|e| *e != child);
+        tree.dormant.insert(0, previous);
+        tree.dormant.truncate(DORMANT_MEMORY);
+        swap_prioritized(world, previous, child);
+    }
+}
@@ -1,9 +1,14 @@
 //! Focusable components and bundles to ease navigable UI declaration.
-use bevy::prelude::{Bundle, ButtonBundle, Component, NodeBundle};
+use bevy::prelude::{
+    default, BackgroundColor, BorderColor, Bundle, ButtonBundle, Changed, Color, Commands,
+    Component, Display, Entity, EventReader, GlobalTransform, Local, Node, NodeBundle, Or,
+    PositionType, Query, Rect, Res, Resource, Style, Time, UiRect, Val, With, Without, ZIndex,
+};
 
 use crate::{
+    events::NavEvent,
     menu::{MenuBuilder, MenuSetting, NavMarker},
-    resolve::Focusable,
+    resolve::{FocusState, Focusable},
 };
 
 /// A button like the default bevy [`ButtonBundle`], but with an added
@@ -73,3 +78,246 @@ impl MenuBundle {
         }
     }
 }
+
+/// Styling of a [`Focusable`] depending on its [`FocusState`].
+///
+/// Add a `FocusStyle` component to your [`Focusable`]s and the
+/// [`apply_focus_style::<S>`](apply_focus_style) system to your app,
+/// instead of matching on [`Focusable::state`] in your own systems.
+///
+/// See [`DefaultFocusStyle`] for a ready-to-use implementation.
+pub trait FocusStyle: Component {
+    /// The [`BackgroundColor`] of the [`Focusable`] in `state`.
+    ///
+    /// `None` leaves the background color unchanged.
+    fn background(&self, state: FocusState) -> Option<Color>;
+
+    /// The [`BorderColor`] of the [`Focusable`] in `state`.
+    ///
+    /// `None`, the default, leaves the border color unchanged.
+    fn border(&self, _state: FocusState) -> Option<Color> {
+        None
+    }
+}
+
+/// The [`FocusStyle`] used in the examples.
+#[derive(Component, Default, Clone, Copy, Debug)]
+pub struct DefaultFocusStyle;
+impl FocusStyle for DefaultFocusStyle {
+    fn background(&self, state: FocusState) -> Option<Color> {
+        Some(match state {
+            FocusState::Focused => Color::ORANGE_RED,
+            FocusState::Active => Color::GOLD,
+            FocusState::Prioritized => Color::GRAY,
+            FocusState::Inert => Color::DARK_GRAY,
+            FocusState::Blocked => Color::BLACK,
+        })
+    }
+}
+
+/// Update the [`BackgroundColor`] and [`BorderColor`] of [`Focusable`]s
+/// with the `S` [`FocusStyle`] when their [`FocusState`] change.
+///
+/// Add it after [`NavRequestSystem`] so that the style is updated
+/// the frame the focus changes.
+///
+/// This only updates colors when the [`Focusable`] or `S` change.
+/// To style additional states, such as hover, run your system
+/// after this one, or return `None` from `S`'s methods.
+///
+/// [`NavRequestSystem`]: crate::NavRequestSystem
+#[allow(clippy::type_complexity)]
+pub fn apply_focus_style<S: FocusStyle>(
+    mut styled: Query<
+        (
+            &Focusable,
+            &S,
+            Option<&mut BackgroundColor>,
+            Option<&mut BorderColor>,
+        ),
+        Or<(Changed<Focusable>, Changed<S>)>,
+    >,
+) {
+    for (focusable, style, background, border) in &mut styled {
+        let state = focusable.state();
+        if let (Some(mut background), Some(color)) = (background, style.background(state)) {
+            background.0 = color;
+        }
+        if let (Some(mut border), Some(color)) = (border, style.border(state)) {
+            border.0 = color;
+        }
+    }
+}
+
+/// Styling of the outline drawn by [`draw_focus_ring`].
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct FocusRing {
+    /// The color of the outline.
+    pub color: Color,
+    /// The width of the outline, in logical pixels.
+    pub thickness: f32,
+    /// How fast the outline moves to a newly focused element.
+    ///
+    /// This is the rate, per second, at which the distance to the
+    /// focused element decays exponentially. `f32::INFINITY` moves it instantly.
+    pub speed: f32,
+}
+impl Default for FocusRing {
+    fn default() -> Self {
+        FocusRing {
+            color: Color::WHITE,
+            thickness: 3.0,
+            speed: 20.0,
+        }
+    }
+}
+
+/// The outline node spawned by [`draw_focus_ring`].
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct FocusRingNode;
+
+/// Draw an outline around the focused [`Focusable`], styled with the
+/// [`FocusRing`] resource.
+///
+/// The outline is a [`FocusRingNode`] spawned on the first run.
+/// It follows [`NavEvent`]s and is hidden when no [`Focusable`] is focused
+/// or when the focused one isn't a UI node.
+///
+/// Add it after [`NavRequestSystem`], with the [`FocusRing`] resource:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// use bevy_ui_navigation::components::{draw_focus_ring, FocusRing};
+///
+/// App::new()
+///     .init_resource::<FocusRing>()
+///     .add_systems(Update, draw_focus_ring.after(NavRequestSystem));
+/// ```
+///
+/// [`NavRequestSystem`]: crate::NavRequestSystem
+#[allow(clippy::too_many_arguments)]
+pub fn draw_focus_ring(
+    mut commands: Commands,
+    mut events: EventReader<NavEvent>,
+    mut target: Local<Option<Entity>>,
+    mut current: Local<Option<Rect>>,
+    ring: Res<FocusRing>,
+    time: Res<Time>,
+    nodes: Query<(&Node, &GlobalTransform), Without<FocusRingNode>>,
+    mut ring_nodes: Query<(&mut Style, &mut BorderColor), With<FocusRingNode>>,
+) {
+    for event in events.read() {
+        match event {
+            NavEvent::FocusLost => *target = None,
+            event => *target = event.focused_entity().or(*target),
+        }
+    }
+    let Ok((mut style, mut border)) = ring_nodes.get_single_mut() else {
+        commands.spawn((
+            FocusRingNode,
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                z_index: ZIndex::Global(i32::MAX),
+                ..default()
+            },
+        ));
+        return;
+    };
+    let Some((node, transform)) = target.and_then(|e| nodes.get(e).ok()) else {
+        if style.display != Display::None {
+            style.display = Display::None;
+        }
+        *current = None;
+        return;
+    };
+    let goal = Rect::from_center_size(transform.translation().truncate(), node.size())
+        .inset(ring.thickness);
+    let rect = match *current {
+        Some(rect) if ring.speed.is_finite() => {
+            let t = 1.0 - (-ring.speed * time.delta_seconds()).exp();
+            let lerp = |from: bevy::math::Vec2, to| from.lerp(to, t);
+            Rect::from_corners(lerp(rect.min, goal.min), lerp(rect.max, goal.max))
+        }
+        _ => goal,
+    };
+    *current = Some(rect);
+
+    let new_style = Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(rect.min.x),
+        top: Val::Px(rect.min.y),
+        width: Val::Px(rect.width()),
+        height: Val::Px(rect.height()),
+        border: UiRect::all(Val::Px(ring.thickness)),
+        ..default()
+    };
+    // Avoid triggering change detection, which would re-compute the layout.
+    if *style != new_style {
+        *style = new_style;
+    }
+    if border.0 != ring.color {
+        border.0 = ring.color;
+    }
+}
+
+/// A "focus intensity" eased toward `1.0` when the [`Focusable`] is
+/// [`FocusState::Focused`] or [`FocusState::Active`], and toward `0.0` otherwise.
+///
+/// Read [`FocusAnim::intensity`] in your own systems to drive a material,
+/// a scale or any other animation. It is updated by [`animate_focus`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct FocusAnim {
+    /// How much focus this [`Focusable`] has, between `0.0` and `1.0`.
+    pub intensity: f32,
+    /// How fast `intensity` moves toward its target.
+    ///
+    /// This is the rate, per second, at which the distance to the
+    /// target decays exponentially. `f32::INFINITY` moves it instantly.
+    pub speed: f32,
+}
+impl Default for FocusAnim {
+    fn default() -> Self {
+        FocusAnim {
+            intensity: 0.0,
+            speed: 10.0,
+        }
+    }
+}
+
+/// Update the [`FocusAnim::intensity`] of [`Focusable`]s.
+///
+/// Add it after [`NavRequestSystem`], and read [`FocusAnim`] after it:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// use bevy_ui_navigation::components::animate_focus;
+///
+/// App::new().add_systems(Update, animate_focus.after(NavRequestSystem));
+/// ```
+///
+/// [`NavRequestSystem`]: crate::NavRequestSystem
+pub fn animate_focus(time: Res<Time>, mut animated: Query<(&Focusable, &mut FocusAnim)>) {
+    for (focusable, mut anim) in &mut animated {
+        let target = match focusable.state() {
+            FocusState::Focused | FocusState::Active => 1.0,
+            _ => 0.0,
+        };
+        if anim.intensity == target {
+            continue;
+        }
+        let t = 1.0 - (-anim.speed * time.delta_seconds()).exp();
+        let intensity = anim.intensity + (target - anim.intensity) * t;
+        // Snap to the target rather than approaching it forever.
+        anim.intensity = if (target - intensity).abs() < 0.001 {
+            target
+        } else {
+            intensity
+        };
+    }
+}
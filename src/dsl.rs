@@ -98,11 +98,7 @@ impl<C: DslBundle> DslBundle for NavigationDsl<C> {
             };
             cmds.insert((menu.setting, builder));
         } else if let Some((action, state)) = self.focusable {
-            let focusable = match action {
-                FocusAction::Normal => Focusable::new(),
-                FocusAction::Cancel => Focusable::cancel(),
-                FocusAction::Lock => Focusable::lock(),
-            };
+            let focusable = Focusable::with_action(action);
             let focusable = match state {
                 DslState::Normal => focusable,
                 DslState::Blocked => focusable.blocked(),
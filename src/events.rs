@@ -16,21 +16,43 @@
 //!   contains specific information about what the navigation system is doing.
 //!
 //! [`Focusable`]: crate::resolve::Focusable
+#[cfg(feature = "bevy_reflect")]
+use bevy::{ecs::reflect::ReflectResource, reflect::Reflect};
+#[cfg(feature = "history")]
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, SendError, Sender};
+use std::time::Duration;
+
 use bevy::{
     ecs::{
         entity::Entity,
-        event::EventReader,
+        event::{EventReader, EventWriter},
         query::{ReadOnlyWorldQuery, WorldQuery},
-        system::Query,
+        system::{Query, Res, ResMut},
     },
-    math::Vec2,
-    prelude::Event,
+    math::{IVec2, Vec2},
+    prelude::{Event, Resource},
+    time::Time,
+    utils::synccell::SyncCell,
 };
 use non_empty_vec::NonEmpty;
 
-use crate::resolve::LockReason;
+use crate::resolve::{FocusId, LockReason};
+
+pub use crate::generic::{Direction, DirectionAxis, ScopeDirection};
 
 /// Requests to send to the navigation system to update focus.
+///
+/// # Processing order
+///
+/// All requests sent in a single frame are processed in the following order:
+/// 1. [`NavRequest::FocusOn`], [`NavRequest::PeekFocus`], [`NavRequest::Lock`],
+///    [`NavRequest::LockUntil`] and [`NavRequest::Unlock`]
+/// 2. All other requests, such as [`NavRequest::Move`] or [`NavRequest::Action`]
+///
+/// Requests in the same category are processed in the order they were sent.
+/// So a `Move` sent in the same frame as a `FocusOn` always moves
+/// from the `FocusOn` target, regardless of which one was sent first.
 #[derive(Debug, PartialEq, Clone, Copy, Event)]
 pub enum NavRequest {
     /// Move in in provided direction according to the plugin's [navigation strategy].
@@ -40,11 +62,33 @@ pub enum NavRequest {
     /// [navigation strategy]: crate::resolve::MenuNavigationStrategy.
     Move(Direction),
 
+    /// Move by several steps at once, `x` columns east and `y` rows south.
+    ///
+    /// Negative values move west and north. This is a sequence of
+    /// [`NavRequest::Move`], first horizontal then vertical, emitting
+    /// a single [`NavEvent::FocusChanged`]. Steps follow the menu's wrapping
+    /// setting, when a step can't be made, focus stops at the last reached
+    /// focusable.
+    ///
+    /// Useful for keys jumping several cells in grid menus.
+    MoveBy(IVec2),
+
     /// Move within the encompassing [`MenuSetting::scope`].
     ///
     /// [`MenuSetting::scope`]: crate::prelude::MenuSetting::scope
     ScopeMove(ScopeDirection),
 
+    /// Move in provided direction within the encompassing [`MenuSetting::scope`].
+    ///
+    /// Like [`NavRequest::ScopeMove`], but for scope menus laid out
+    /// as a 2d grid, such as a grid of tabs.
+    /// The movement is resolved with the plugin's [navigation strategy]
+    /// over the focusables of the scope menu.
+    ///
+    /// [`MenuSetting::scope`]: crate::prelude::MenuSetting::scope
+    /// [navigation strategy]: crate::resolve::MenuNavigationStrategy
+    ScopeMove2d(Direction),
+
     /// Activate the currently focused [`Focusable`].
     ///
     /// If a menu is _[reachable from]_
@@ -53,11 +97,87 @@ pub enum NavRequest {
     /// [reachable from]: crate::menu::MenuBuilder::NamedParent
     Action,
 
+    /// Like [`NavRequest::Action`], but sent by a specific kind of input.
+    ///
+    /// Does nothing if the focused [`Focusable`] has an [`ActionSources`]
+    /// not accepting `InputSource`, otherwise it is handled exactly like
+    /// [`NavRequest::Action`], including in the emitted [`NavEvent`]s.
+    ///
+    /// The default input systems send this rather than `Action`.
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    /// [`ActionSources`]: crate::resolve::ActionSources
+    ActionFrom(InputSource),
+
+    /// A secondary action on the currently focused [`Focusable`],
+    /// such as "inspect" when [`NavRequest::Action`] is "equip".
+    ///
+    /// Never changes focus, emits a [`NavEvent::SecondaryActivated`].
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    SecondaryAction,
+
+    /// A tertiary action on the currently focused [`Focusable`].
+    ///
+    /// Never changes focus, emits a [`NavEvent::TertiaryActivated`].
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    TertiaryAction,
+
+    /// Add or remove the focused [`Selectable`] from the selection,
+    /// for example to pick several items in an inventory.
+    ///
+    /// Never changes focus, toggles the [`Selected`] marker of the focused
+    /// element and emits a [`NavEvent::SelectionChanged`]. Emits
+    /// a [`NavEvent::NoChanges`] if the focused element is not [`Selectable`].
+    ///
+    /// [`Selectable`]: crate::resolve::Selectable
+    /// [`Selected`]: crate::resolve::Selected
+    ToggleSelect,
+
     /// Leave this submenu to enter the one it is _[reachable from]_.
     ///
     /// [reachable from]: crate::menu::MenuBuilder::NamedParent
     Cancel,
 
+    /// Leave all submenus to enter the root menu.
+    ///
+    /// Like sending [`NavRequest::Cancel`] until reaching the root menu,
+    /// but emits a single [`NavEvent::FocusChanged`], with the whole path
+    /// from the focused [`Focusable`] to the root menu as `from`.
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    CancelToRoot,
+
+    /// Focus the next root menu, for example to switch between
+    /// sections of the UI with shoulder buttons.
+    ///
+    /// Root menus are [`MenuBuilder::Root`] menus, ordered by [`Entity::index`],
+    /// so usually in spawn order. The focus goes to the element of the next
+    /// root menu that was focused last, wrapping from the last root menu to the
+    /// first one. Emits a [`NavEvent::NoChanges`] if there is a single root menu
+    /// or when the focused element is not within a menu.
+    ///
+    /// [`MenuBuilder::Root`]: crate::menu::MenuBuilder::Root
+    NextRoot,
+
+    /// Focus the previous root menu, see [`NavRequest::NextRoot`].
+    PrevRoot,
+
+    /// Emit a [`NavEvent::FocusChanged`] for the current focus,
+    /// without changing it.
+    ///
+    /// Both `to` and `from` are the path from the focused [`Focusable`]
+    /// to the root menu. No [`FocusState`] is changed.
+    ///
+    /// Useful to synchronize systems reacting to [`NavEvent`]s that
+    /// missed the previous focus changes, for example after rebuilding
+    /// part of the UI.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    /// [`FocusState`]: crate::resolve::FocusState
+    Refocus,
+
     /// Move the focus to any arbitrary [`Focusable`] entity.
     ///
     /// Note that resolving a `FocusOn` request is expensive,
@@ -65,15 +185,48 @@ pub enum NavRequest {
     /// Avoid sending FocusOn messages when you know the target entity is
     /// already focused.
     ///
+    /// The target may be in a menu tree disjoint from the currently focused
+    /// one (for example, under another [`MenuBuilder::Root`] menu).
+    /// In this case, all the elements leading to the previously focused
+    /// `Focusable` become inactive.
+    ///
+    /// [`MenuBuilder::Root`]: crate::menu::MenuBuilder::Root
     /// [`Focusable`]: crate::resolve::Focusable
     FocusOn(Entity),
 
+    /// Temporarily focus any arbitrary [`Focusable`] entity,
+    /// for example to preview the element under the mouse cursor.
+    ///
+    /// Only the target becomes [`FocusState::Focused`], the previously
+    /// focused element becomes [`FocusState::Prioritized`].
+    /// Unlike [`NavRequest::FocusOn`], this doesn't change the active trail:
+    /// no menus are entered or left and the element focused when entering
+    /// a menu is not altered.
+    ///
+    /// The previous focus is restored before handling the next
+    /// request that is not a `PeekFocus`. A [`NavEvent::FocusChanged`]
+    /// is emitted both when peeking and when restoring.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    /// [`FocusState::Focused`]: crate::resolve::FocusState::Focused
+    /// [`FocusState::Prioritized`]: crate::resolve::FocusState::Prioritized
+    PeekFocus(Entity),
+
     /// Locks the navigation system.
     ///
     /// A [`NavEvent::Locked`] will be emitted as a response if the
     /// navigation system was not already locked.
     Lock,
 
+    /// Locks the navigation system until the provided request
+    /// or a [`NavRequest::Unlock`] is received.
+    ///
+    /// Like [`NavRequest::Lock`], but the provided request unlocks
+    /// the navigation system too, for example `LockUntil(UnlockWith::Cancel)`
+    /// for a popup closed with the cancel button. It is then handled
+    /// as a [`NavRequest::Unlock`], not as itself.
+    LockUntil(UnlockWith),
+
     /// Unlocks the navigation system.
     ///
     /// A [`NavEvent::Unlocked`] will be emitted as a response if the
@@ -81,40 +234,102 @@ pub enum NavRequest {
     Unlock,
 }
 
-/// Direction for movement in [`MenuSetting::scope`] menus.
-///
-/// [`MenuSetting::scope`]: crate::menu::MenuSetting
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum ScopeDirection {
-    /// The next focusable in menu, usually goes right.
-    Next,
-
-    /// The previous focusable in menu, usually goes left.
-    Previous,
+/// The request unlocking navigation locked with [`NavRequest::LockUntil`],
+/// besides [`NavRequest::Unlock`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum UnlockWith {
+    /// [`NavRequest::Action`], from any [`InputSource`].
+    Action,
+    /// [`NavRequest::SecondaryAction`].
+    SecondaryAction,
+    /// [`NavRequest::TertiaryAction`].
+    TertiaryAction,
+    /// [`NavRequest::Cancel`].
+    Cancel,
+    /// [`NavRequest::CancelToRoot`].
+    CancelToRoot,
+}
+impl UnlockWith {
+    /// Whether `request` is this unlock request.
+    pub fn matches(&self, request: NavRequest) -> bool {
+        use NavRequest as Req;
+        match self {
+            UnlockWith::Action => request.is_action(),
+            UnlockWith::SecondaryAction => request == Req::SecondaryAction,
+            UnlockWith::TertiaryAction => request == Req::TertiaryAction,
+            UnlockWith::Cancel => request == Req::Cancel,
+            UnlockWith::CancelToRoot => request == Req::CancelToRoot,
+        }
+    }
+}
+
+/// The kind of input that sent a [`NavRequest::ActionFrom`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InputSource {
+    /// A keyboard key.
+    Keyboard,
+    /// A gamepad button.
+    Gamepad,
+    /// A mouse click or touch.
+    Pointer,
 }
 
-/// 2d direction to move in normal menus
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum Direction {
-    /// Down.
-    South,
-    /// Up.
-    North,
-    /// Right.
-    East,
-    /// Left.
-    West,
+impl NavRequest {
+    /// Whether this is [`NavRequest::Action`] or [`NavRequest::ActionFrom`].
+    pub(crate) fn is_action(&self) -> bool {
+        matches!(self, NavRequest::Action | NavRequest::ActionFrom(_))
+    }
+
+    /// The category of this request, lower categories are processed first.
+    ///
+    /// See [`NavRequest`]'s "Processing order" section.
+    pub(crate) fn processing_order(&self) -> u8 {
+        use NavRequest::*;
+        match self {
+            FocusOn(_) | PeekFocus(_) | Lock | LockUntil(_) | Unlock => 0,
+            Move(_) | MoveBy(_) | ScopeMove(_) | ScopeMove2d(_) | Action | ActionFrom(_)
+            | SecondaryAction | TertiaryAction | ToggleSelect | Cancel | CancelToRoot
+            | NextRoot | PrevRoot | Refocus => 1,
+        }
+    }
 }
+
 impl Direction {
     /// Is `other` in direction `self` from `reference`?
     pub fn is_in(&self, reference: Vec2, other: Vec2) -> bool {
         let coord = other - reference;
+        self.contains_offset(coord.x, coord.y)
+    }
+
+    /// The cardinal direction `delta` points to, each covering a 90° sector.
+    ///
+    /// Unlike [`Direction::is_in`], the `y` axis points upward,
+    /// like gamepad sticks.
+    pub fn from_vec2(delta: Vec2) -> Self {
         use Direction::*;
-        match self {
-            North => coord.y < coord.x && coord.y < -coord.x,
-            South => coord.y > coord.x && coord.y > -coord.x,
-            East => coord.y < coord.x && coord.y > -coord.x,
-            West => coord.y > coord.x && coord.y < -coord.x,
+        match () {
+            () if delta.y < delta.x && delta.y < -delta.x => South,
+            () if delta.y < delta.x => East,
+            () if delta.y >= delta.x && delta.y > -delta.x => North,
+            () => West,
+        }
+    }
+
+    /// Like [`Direction::from_vec2`], but including diagonals,
+    /// each of the eight directions covering a 45° sector.
+    pub fn from_vec2_diagonal(delta: Vec2) -> Self {
+        use Direction::*;
+        let sector = (delta.y.atan2(delta.x) / std::f32::consts::FRAC_PI_4).round() as i32;
+        match sector.rem_euclid(8) {
+            0 => East,
+            1 => NorthEast,
+            2 => North,
+            3 => NorthWest,
+            4 => West,
+            5 => SouthWest,
+            6 => South,
+            _ => SouthEast,
         }
     }
 }
@@ -140,6 +355,29 @@ pub enum NavEvent {
     /// [`Focusable`]: crate::resolve::Focusable
     InitiallyFocused(Entity),
 
+    /// The full active path when the first element is focused,
+    /// sent right after [`NavEvent::InitiallyFocused`].
+    ///
+    /// Useful to open the right tabs and panels at startup
+    /// in one go, rather than reconstructing the menu tree.
+    TreeInitialized {
+        /// The active elements, ascending: `path.first()` is the focused
+        /// element and `path.last()` is in the root menu.
+        path: NonEmpty<Entity>,
+    },
+
+    /// All [`Focusable`]s were despawned, nothing is focused anymore.
+    ///
+    /// For example, after a scene transition. The navigation lock, if set
+    /// by a [lock focusable], is released.
+    ///
+    /// When [`Focusable`]s are spawned again, a [`NavEvent::InitiallyFocused`]
+    /// is sent.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    /// [lock focusable]: crate::resolve::Focusable::lock
+    FocusLost,
+
     /// Focus changed.
     ///
     /// ## Notes
@@ -157,6 +395,23 @@ pub enum NavEvent {
         /// The list of active elements from the focused one to the last
         /// active which is affected by the focus change
         from: NonEmpty<Entity>,
+        /// The position of the previously focused element, `from.first()`,
+        /// according to its `GlobalTransform`.
+        ///
+        /// Useful to tween a cursor between focused elements.
+        /// `None` if it doesn't have a `GlobalTransform`.
+        from_position: Option<Vec2>,
+        /// The position of the newly focused element, `to.first()`,
+        /// according to its `GlobalTransform`.
+        ///
+        /// `None` if it doesn't have a `GlobalTransform`.
+        to_position: Option<Vec2>,
+        /// Whether `from.first()` and `to.first()` are in the same menu,
+        /// for example when moving between siblings with [`NavRequest::Move`].
+        ///
+        /// `false` when entering or leaving a menu. Useful to play a subtle
+        /// sound within a menu, and a more prominent one when changing menus.
+        within_menu: bool,
     },
 
     /// The [`NavRequest`] didn't lead to any change in focus.
@@ -172,7 +427,8 @@ pub enum NavEvent {
     /// Either by a [lock focusable] or [`NavRequest::Lock`].
     ///
     /// Once the navigation plugin enters a locked state, the only way to exit
-    /// it is to send a [`NavRequest::Unlock`].
+    /// it is to send a [`NavRequest::Unlock`], or the request
+    /// of a [`NavRequest::LockUntil`].
     ///
     /// [lock]: crate::resolve::NavLock
     /// [lock focusable]: crate::resolve::Focusable::lock
@@ -185,18 +441,214 @@ pub enum NavEvent {
     ///
     /// [lock]: crate::resolve::NavLock
     Unlocked(LockReason),
+
+    /// A [`NavRequest::ScopeMove`] reached the first or last [`Focusable`]
+    /// of a [`MenuSetting::scope`] menu that doesn't wrap.
+    ///
+    /// Useful to grey out the "next" arrow of a tab bar when reaching the last tab.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    /// [`MenuSetting::scope`]: crate::menu::MenuSetting::scope
+    ScopeEdge {
+        /// The scope menu.
+        menu: Entity,
+        /// The direction of the request that hit the edge.
+        direction: ScopeDirection,
+    },
+
+    /// A [`Toggleable`] was flipped by a [`NavRequest::Action`].
+    ///
+    /// [`Toggleable`]: crate::resolve::Toggleable
+    Toggled {
+        /// The toggled [`Focusable`](crate::resolve::Focusable).
+        entity: Entity,
+        /// The new value of the [`Toggleable`](crate::resolve::Toggleable).
+        value: bool,
+    },
+
+    /// The [`Focusable`] received a [`NavRequest::SecondaryAction`].
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    SecondaryActivated(Entity),
+
+    /// The [`Focusable`] received a [`NavRequest::TertiaryAction`].
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    TertiaryActivated(Entity),
+
+    /// A [`NavRequest::ToggleSelect`] added or removed a [`Selectable`]
+    /// from the selection.
+    ///
+    /// [`Selectable`]: crate::resolve::Selectable
+    SelectionChanged {
+        /// The focused [`Selectable`](crate::resolve::Selectable).
+        entity: Entity,
+        /// Whether `entity` is now [`Selected`](crate::resolve::Selected).
+        selected: bool,
+    },
+
+    /// The [lock] changed, with the new [`NavLock::reason`],
+    /// `None` when navigation is now unlocked.
+    ///
+    /// Unlike [`NavEvent::Locked`] and [`NavEvent::Unlocked`], this is
+    /// emitted whatever changed the lock, including `restore_nav`.
+    /// It is sent after the [`NavRequestSystem`].
+    ///
+    /// [lock]: crate::resolve::NavLock
+    /// [`NavLock::reason`]: crate::resolve::NavLock::reason
+    /// [`NavRequestSystem`]: crate::NavRequestSystem
+    LockChanged(Option<LockReason>),
+
+    /// A menu with [`MenuSetting::engage_required`] was engaged
+    /// or disengaged.
+    ///
+    /// [`MenuSetting::engage_required`]: crate::menu::MenuSetting::engage_required
+    Engaged {
+        /// The menu.
+        menu: Entity,
+        /// Whether [`NavRequest::Move`] now works within `menu`.
+        engaged: bool,
+    },
 }
 impl NavEvent {
     /// Create a `FocusChanged` with a single `to`
     ///
     /// Usually the `NavEvent::FocusChanged.to` field has a unique value.
     pub(crate) fn focus_changed(to: Entity, from: NonEmpty<Entity>) -> NavEvent {
+        NavEvent::path_changed(NonEmpty::new(to), from)
+    }
+
+    /// Create a `FocusChanged` without positions.
+    ///
+    /// Positions and `within_menu` are filled in by the navigation system
+    /// before sending the event.
+    pub(crate) fn path_changed(to: NonEmpty<Entity>, from: NonEmpty<Entity>) -> NavEvent {
         NavEvent::FocusChanged {
             from,
-            to: NonEmpty::new(to),
+            to,
+            from_position: None,
+            to_position: None,
+            within_menu: false,
         }
     }
 
+    /// The focused entity after this event.
+    ///
+    /// - `to.first()` for [`NavEvent::FocusChanged`]
+    /// - `from.first()` for [`NavEvent::NoChanges`]
+    /// - The entity of [`NavEvent::InitiallyFocused`], [`NavEvent::Toggled`],
+    ///   [`NavEvent::SecondaryActivated`], [`NavEvent::TertiaryActivated`]
+    ///   and [`NavEvent::SelectionChanged`]
+    /// - `path.first()` for [`NavEvent::TreeInitialized`]
+    /// - The locking [`Focusable`] for [`NavEvent::Locked`] and [`NavEvent::Unlocked`]
+    ///
+    /// `None` for locks not triggered by a [`Focusable`], [`NavEvent::ScopeEdge`],
+    /// [`NavEvent::LockChanged`], [`NavEvent::Engaged`] and [`NavEvent::FocusLost`].
+    ///
+    /// ```rust
+    /// # use bevy_ui_navigation::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn print_focus(mut events: EventReader<NavEvent>) {
+    ///     for focused in events.read().filter_map(NavEvent::focused_entity) {
+    ///         println!("Focused {focused:?}");
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    pub fn focused_entity(&self) -> Option<Entity> {
+        use NavEvent::*;
+        match self {
+            FocusChanged { to, .. } => Some(*to.first()),
+            NoChanges { from, .. } => Some(*from.first()),
+            InitiallyFocused(entity)
+            | Toggled { entity, .. }
+            | SelectionChanged { entity, .. }
+            | SecondaryActivated(entity)
+            | TertiaryActivated(entity) => Some(*entity),
+            TreeInitialized { path } => Some(*path.first()),
+            Locked(LockReason::Focusable(entity)) | Unlocked(LockReason::Focusable(entity)) => {
+                Some(*entity)
+            }
+            Locked(_)
+            | Unlocked(_)
+            | LockChanged(_)
+            | ScopeEdge { .. }
+            | Engaged { .. }
+            | FocusLost => None,
+        }
+    }
+
+    /// The [`FocusId`] of the [`Self::focused_entity`], if it has one in `ids`.
+    ///
+    /// ```rust
+    /// # use bevy_ui_navigation::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn play_focus_sound(mut events: EventReader<NavEvent>, ids: Query<&FocusId>) {
+    ///     for event in events.read() {
+    ///         if let (NavEvent::FocusChanged { .. }, Some(FocusId(id))) = (event, event.focused_id(&ids)) {
+    ///             println!("Play the sound of {id}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn focused_id<F: ReadOnlyWorldQuery>(&self, ids: &Query<&FocusId, F>) -> Option<FocusId> {
+        ids.get(self.focused_entity()?).ok().copied()
+    }
+
+    /// The `from` and `to` paths of this event, as slices.
+    ///
+    /// - [`NavEvent::FocusChanged`]: `(from, to)`
+    /// - [`NavEvent::NoChanges`]: `(from, [])`
+    /// - [`NavEvent::TreeInitialized`]: `([], path)`
+    ///
+    /// Other events have no paths and return two empty slices.
+    ///
+    /// ```rust
+    /// # use bevy_ui_navigation::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn print_activated(mut events: EventReader<NavEvent>) {
+    ///     for event in events.read() {
+    ///         let (_, to) = event.paths();
+    ///         let newly_active: Vec<Entity> = to.to_vec();
+    ///         println!("Now active: {newly_active:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn paths(&self) -> (&[Entity], &[Entity]) {
+        use NavEvent::*;
+        match self {
+            FocusChanged { from, to, .. } => (from, to),
+            NoChanges { from, .. } => (from, &[]),
+            TreeInitialized { path } => (&[], path),
+            InitiallyFocused(_)
+            | FocusLost
+            | Locked(_)
+            | Unlocked(_)
+            | LockChanged(_)
+            | ScopeEdge { .. }
+            | Engaged { .. }
+            | Toggled { .. }
+            | SelectionChanged { .. }
+            | SecondaryActivated(_)
+            | TertiaryActivated(_) => (&[], &[]),
+        }
+    }
+
+    /// Iterate over the `from` path of this event, see [`NavEvent::paths`].
+    ///
+    /// Starts with the previously focused element.
+    pub fn iter_from(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.paths().0.iter().copied()
+    }
+
+    /// Iterate over the `to` path of this event, see [`NavEvent::paths`].
+    ///
+    /// Starts with the newly focused element.
+    pub fn iter_to(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.paths().1.iter().copied()
+    }
+
     /// Whether this event is a [`NavEvent::NoChanges`]
     /// triggered by a [`NavRequest::Action`]
     /// if `entity` is the currently focused element.
@@ -205,6 +657,253 @@ impl NavEvent {
     }
 }
 
+/// The net focus change of the last run of the navigation system.
+///
+/// Useful if you only care about which entity ends up focused
+/// after all [`NavRequest`]s of a frame are handled,
+/// rather than each individual [`NavEvent`].
+///
+/// This is opt-in: the navigation system only updates it if it exists.
+/// Add it to your app with `app.init_resource::<FrameNavSummary>()`.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct FrameNavSummary {
+    /// The focused entity after all [`NavRequest`]s were handled,
+    /// `None` if nothing is focused.
+    pub final_focus: Option<Entity>,
+    /// Whether `final_focus` differs from the focused entity
+    /// of the previous run.
+    pub changed: bool,
+    /// How many menus deep `final_focus` is, `0` when it is
+    /// in the root menu or in no menus.
+    pub menu_depth: usize,
+}
+
+/// The focused entity and the active ones leading to it,
+/// from the root menu down to the focused entity.
+///
+/// Useful to display a breadcrumb of the menus the player went through.
+/// Empty when nothing is focused.
+///
+/// This is opt-in: the navigation system only updates it if it exists.
+/// Add it to your app with `app.init_resource::<ActiveTrail>()`.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct ActiveTrail(pub Vec<Entity>);
+
+/// Process consecutive identical [`NavRequest`]s sent in the same frame once.
+///
+/// By default, every request is processed: five `Move(East)` sent in
+/// a single frame move the focus five times. With this resource, they
+/// move it once. Useful when an input system may send the same request
+/// several times a frame, to avoid over-scrolling.
+///
+/// Requests are consecutive in their [processing order](NavRequest#processing-order),
+/// so `Move(East)`, `FocusOn(entity)`, `Move(East)` moves once from `entity`.
+///
+/// This is opt-in: the navigation system only coalesces requests if it exists.
+/// Add it to your app with `app.init_resource::<CoalesceNavRequests>()`.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct CoalesceNavRequests;
+
+/// How long the navigation has been at rest.
+///
+/// Useful to start idle animations or an attract mode after some
+/// time without player input, see [`NavIdle::is_idle_for`].
+///
+/// This is opt-in: the navigation system only updates it if it exists.
+/// Add it to your app with `app.init_resource::<NavIdle>()`.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct NavIdle {
+    /// Time since the last [`NavEvent::FocusChanged`].
+    pub since: Duration,
+    /// Whether any [`NavRequest`] resets `since`, even if it doesn't
+    /// change the focus, such as a [`NavRequest::Move`] into a wall.
+    pub reset_on_any_request: bool,
+}
+impl NavIdle {
+    /// Whether the navigation has been at rest for at least `duration`.
+    pub fn is_idle_for(&self, duration: Duration) -> bool {
+        self.since >= duration
+    }
+}
+
+/// Update [`NavIdle`] with the [`NavRequest`]s and [`NavEvent`]s of this frame.
+pub(crate) fn update_nav_idle(
+    mut idle: ResMut<NavIdle>,
+    time: Res<Time>,
+    mut requests: EventReader<NavRequest>,
+    mut events: EventReader<NavEvent>,
+) {
+    let requested = requests.read().count() != 0;
+    let changed = events
+        .read()
+        .any(|event| matches!(event, NavEvent::FocusChanged { .. }));
+    if changed || (requested && idle.reset_on_any_request) {
+        idle.since = Duration::ZERO;
+    } else {
+        idle.since += time.delta();
+    }
+}
+
+/// A [`NavRequest`] handled by the navigation system,
+/// with the [`NavEvent`]s it caused.
+#[cfg(feature = "history")]
+#[derive(Debug, Clone)]
+pub struct NavHistoryEntry {
+    /// The handled request.
+    pub request: NavRequest,
+    /// The events emitted while handling `request`, may be empty.
+    pub events: Vec<NavEvent>,
+}
+
+/// The last [`NavRequest`]s handled by the navigation system,
+/// and the [`NavEvent`]s they caused.
+///
+/// Useful to reproduce navigation bugs: [`NavHistory::dump`] the log
+/// when something goes wrong, or feed [`NavHistory::requests`] back
+/// to a fresh `App` to replay them.
+///
+/// This is opt-in: the navigation system only records requests if it exists.
+/// Add it to your app with `app.init_resource::<NavHistory>()`
+/// or `app.insert_resource(NavHistory::new(max_len))`.
+///
+/// Requires the `history` feature.
+#[cfg(feature = "history")]
+#[derive(Resource, Debug, Clone)]
+pub struct NavHistory {
+    entries: VecDeque<NavHistoryEntry>,
+    max_len: usize,
+}
+#[cfg(feature = "history")]
+impl Default for NavHistory {
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+#[cfg(feature = "history")]
+impl NavHistory {
+    /// Record up to `max_len` requests, forgetting the oldest ones.
+    pub fn new(max_len: usize) -> Self {
+        NavHistory {
+            entries: VecDeque::with_capacity(max_len),
+            max_len,
+        }
+    }
+    /// How many requests this remembers at most.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+    /// The recorded entries, from oldest to newest.
+    pub fn entries(&self) -> impl Iterator<Item = &NavHistoryEntry> + '_ {
+        self.entries.iter()
+    }
+    /// The recorded requests, from oldest to newest.
+    ///
+    /// Send them in order to replay the recorded navigation.
+    /// Note that requests ignored because of a [`NavRequest::Lock`]
+    /// are not recorded.
+    pub fn requests(&self) -> impl Iterator<Item = NavRequest> + '_ {
+        self.entries.iter().map(|entry| entry.request)
+    }
+    /// A human-readable log of the recorded requests and events,
+    /// one request per line followed by its events.
+    pub fn dump(&self) -> String {
+        use std::fmt::Write;
+
+        let mut log = String::new();
+        for NavHistoryEntry { request, events } in &self.entries {
+            let _ = writeln!(log, "{request:?}");
+            for event in events {
+                let _ = writeln!(log, "  -> {event:?}");
+            }
+        }
+        log
+    }
+    /// Forget all recorded requests.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    /// Start recording the events of `request`.
+    pub(crate) fn begin(&mut self, request: NavRequest) {
+        if self.entries.len() == self.max_len {
+            self.entries.pop_front();
+        }
+        if self.max_len != 0 {
+            let events = Vec::new();
+            self.entries.push_back(NavHistoryEntry { request, events });
+        }
+    }
+    /// Record `event` as caused by the last [`NavHistory::begin`] request.
+    pub(crate) fn record(&mut self, event: &NavEvent) {
+        if let Some(entry) = self.entries.back_mut() {
+            entry.events.push(event.clone());
+        }
+    }
+}
+
+/// A handle to send [`NavRequest`]s from outside the bevy schedule.
+///
+/// Use it to drive navigation from async tasks, a scripting backend or
+/// any other thread. Get one from the world and clone it as many times
+/// as you need:
+///
+/// ```rust
+/// # use bevy_ui_navigation::{events::NavRequestSender, prelude::*};
+/// # use bevy::prelude::*;
+/// fn spawn_script(sender: Res<NavRequestSender>) {
+///     let sender = sender.clone();
+///     std::thread::spawn(move || {
+///         // Returns an error if the `App` was dropped.
+///         let _ = sender.send(NavRequest::Action);
+///     });
+/// }
+/// ```
+///
+/// # Ordering
+///
+/// Requests sent through this handle are forwarded to `Events<NavRequest>`
+/// in the `PreUpdate` schedule. This means they are handled by the
+/// [`NavRequestSystem`] of the same frame, before any request sent by
+/// your systems in `Update`.
+///
+/// Requests sent while the `PreUpdate` schedule runs may be handled
+/// next frame.
+///
+/// [`NavRequestSystem`]: crate::NavRequestSystem
+#[derive(Resource, Clone, Debug)]
+pub struct NavRequestSender(Sender<NavRequest>);
+impl NavRequestSender {
+    /// Send `request` to the navigation system.
+    ///
+    /// Fails if the `App` owning the navigation system was dropped.
+    pub fn send(&self, request: NavRequest) -> Result<(), SendError<NavRequest>> {
+        self.0.send(request)
+    }
+}
+
+/// The receiving end of [`NavRequestSender`].
+#[derive(Resource)]
+pub(crate) struct NavRequestReceiver(SyncCell<Receiver<NavRequest>>);
+
+/// Create a connected [`NavRequestSender`] and [`NavRequestReceiver`] pair.
+pub(crate) fn nav_request_channel() -> (NavRequestSender, NavRequestReceiver) {
+    let (sender, receiver) = mpsc::channel();
+    let receiver = NavRequestReceiver(SyncCell::new(receiver));
+    (NavRequestSender(sender), receiver)
+}
+
+/// Forward [`NavRequest`]s sent through a [`NavRequestSender`]
+/// to `Events<NavRequest>`.
+pub(crate) fn drain_nav_request_channel(
+    mut receiver: ResMut<NavRequestReceiver>,
+    mut requests: EventWriter<NavRequest>,
+) {
+    requests.send_batch(receiver.0.get().try_iter());
+}
+
 /// Extend [`EventReader<NavEvent>`] with methods
 /// to simplify working with [`NavEvent`]s.
 ///
@@ -214,11 +913,107 @@ impl NavEvent {
 pub trait NavEventReaderExt<'w, 's> {
     /// Create a [`NavEventReader`] from this event reader.
     fn nav_iter(&mut self) -> NavEventReader<'w, 's, '_>;
+
+    /// The last _activated_ [`Focusable`] this frame, if any.
+    ///
+    /// See [`NavEventReader::activated`] for meaning of _"activated"_.
+    ///
+    /// Note that this consumes all events in the reader.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy_ui_navigation::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn start_game(mut events: EventReader<NavEvent>, start: Query<(), With<Button>>) {
+    ///     if let Some(activated) = events.activated_entity() {
+    ///         if start.contains(activated) {
+    ///             // …
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    fn activated_entity(&mut self) -> Option<Entity> {
+        self.nav_iter().activated().last()
+    }
+
+    /// The last focused [`Focusable`] when a [`NavRequest::Cancel`]
+    /// didn't lead to any change in focus this frame, if any.
+    ///
+    /// This happens when cancelling from the root menu, for example
+    /// to close a pause menu.
+    ///
+    /// Note that this consumes all events in the reader.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    fn cancelled_entity(&mut self) -> Option<Entity> {
+        self.nav_iter().with_request(NavRequest::Cancel).last()
+    }
+
+    /// The last newly focused entity of [`NavEvent::FocusChanged`]s this frame,
+    /// if at least `min_interval` passed since the last one returned.
+    ///
+    /// Useful to avoid spamming sounds or haptics when focus changes rapidly,
+    /// for example when holding the gamepad stick. `throttle` tracks when
+    /// the last focus change was returned, store it in a `Local`.
+    ///
+    /// Note that this consumes all events in the reader.
+    ///
+    /// ```rust
+    /// # use bevy_ui_navigation::{events::FocusThrottle, prelude::*};
+    /// # use bevy::prelude::*;
+    /// # use std::time::Duration;
+    /// fn focus_sound(
+    ///     mut events: EventReader<NavEvent>,
+    ///     mut throttle: Local<FocusThrottle>,
+    ///     time: Res<Time>,
+    /// ) {
+    ///     let min_interval = Duration::from_millis(80);
+    ///     if let Some(focused) = events.focus_changed_throttled(min_interval, &mut throttle, &time) {
+    ///         println!("Play a sound for {focused:?}");
+    ///     }
+    /// }
+    /// ```
+    fn focus_changed_throttled(
+        &mut self,
+        min_interval: Duration,
+        throttle: &mut FocusThrottle,
+        time: &Time,
+    ) -> Option<Entity>;
+}
+
+/// When [`NavEventReaderExt::focus_changed_throttled`] last returned
+/// a focus change.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FocusThrottle {
+    last_change: Option<Duration>,
 }
 impl<'w, 's> NavEventReaderExt<'w, 's> for EventReader<'w, 's, NavEvent> {
     fn nav_iter(&mut self) -> NavEventReader<'w, 's, '_> {
         NavEventReader { event_reader: self }
     }
+
+    fn focus_changed_throttled(
+        &mut self,
+        min_interval: Duration,
+        throttle: &mut FocusThrottle,
+        time: &Time,
+    ) -> Option<Entity> {
+        let focused = self.read().filter_map(|event| match event {
+            NavEvent::FocusChanged { to, .. } => Some(*to.first()),
+            _ => None,
+        });
+        let focused = focused.last()?;
+        let now = time.elapsed();
+        let elapsed = throttle.last_change.map(|last| now.saturating_sub(last));
+        if elapsed.is_some_and(|elapsed| elapsed < min_interval) {
+            return None;
+        }
+        throttle.last_change = Some(now);
+        Some(focused)
+    }
 }
 
 /// A wrapper for `EventReader<NavEvent>` to simplify dealing with [`NavEvent`]s.
@@ -253,7 +1048,10 @@ impl<'w, 's, 'a> NavEventReader<'w, 's, 'a> {
     /// Iterate over [`NavEvent`]s, associating them
     /// with the "relevant" entity of the event.
     pub fn types(&mut self) -> impl Iterator<Item = (&NavEvent, Entity)> + '_ {
-        use NavEvent::{FocusChanged, InitiallyFocused, Locked, NoChanges, Unlocked};
+        use NavEvent::{
+            Engaged, FocusChanged, InitiallyFocused, Locked, NoChanges, ScopeEdge,
+            SelectionChanged, Toggled, Unlocked,
+        };
         self.event_reader.read().filter_map(|event| {
             let entity = match event {
                 NoChanges { from, .. } => Some(*from.first()),
@@ -261,6 +1059,8 @@ impl<'w, 's, 'a> NavEventReader<'w, 's, 'a> {
                 FocusChanged { from, .. } => Some(*from.first()),
                 Locked(LockReason::Focusable(from)) => Some(*from),
                 Unlocked(LockReason::Focusable(from)) => Some(*from),
+                Toggled { entity, .. } | SelectionChanged { entity, .. } => Some(*entity),
+                ScopeEdge { menu, .. } | Engaged { menu, .. } => Some(*menu),
                 _ => None,
             };
             entity.map(|e| (event, e))
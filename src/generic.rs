@@ -0,0 +1,319 @@
+//! UI-agnostic navigation algorithms.
+//!
+//! This module doesn't depend on `bevy`, it contains the pure functions
+//! used by the [resolution algorithm] to move focus within menus:
+//! * [`Direction::contains_offset`]: whether a point is in a given direction.
+//! * [`Direction::opposite`] and [`ScopeDirection::reverse`]: the other way around.
+//! * [`resolve_index`]: cycling through [scope menus].
+//! * [`resolve_scope`]: the next or previous element in a list.
+//! * [`trim_common_tail`]: diffing the paths from two focusables to the root menu.
+//! * [`type_ahead_index`]: jumping to a focusable by typing its name.
+//!
+//! You can use them to implement your own navigation outside of `bevy`.
+//!
+//! [resolution algorithm]: crate::events::NavRequest
+//! [scope menus]: crate::menu::MenuSetting::scope
+use std::num::NonZeroUsize;
+
+use non_empty_vec::NonEmpty;
+
+/// Direction for movement in [`MenuSetting::scope`] menus.
+///
+/// [`MenuSetting::scope`]: crate::menu::MenuSetting
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ScopeDirection {
+    /// The next focusable in menu, usually goes right.
+    Next,
+
+    /// The previous focusable in menu, usually goes left.
+    Previous,
+}
+
+impl ScopeDirection {
+    /// The other direction: `Next` for `Previous` and `Previous` for `Next`.
+    pub fn reverse(&self) -> Self {
+        match self {
+            ScopeDirection::Next => ScopeDirection::Previous,
+            ScopeDirection::Previous => ScopeDirection::Next,
+        }
+    }
+}
+
+/// The axis a cardinal [`Direction`] moves along, see [`Direction::axis`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DirectionAxis {
+    /// East and west.
+    Horizontal,
+    /// North and south.
+    Vertical,
+}
+
+/// 2d direction to move in normal menus
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    /// Down.
+    South,
+    /// Up.
+    North,
+    /// Right.
+    East,
+    /// Left.
+    West,
+    /// Up and right.
+    NorthEast,
+    /// Up and left.
+    NorthWest,
+    /// Down and right.
+    SouthEast,
+    /// Down and left.
+    SouthWest,
+}
+impl Direction {
+    /// Is the `(x, y)` offset within the 90° cone pointing toward `self`?
+    ///
+    /// The `y` axis points downward, like in `bevy_ui`.
+    pub fn contains_offset(&self, x: f32, y: f32) -> bool {
+        use Direction::*;
+        match self {
+            North => y < x && y < -x,
+            South => y > x && y > -x,
+            East => y < x && y > -x,
+            West => y > x && y < -x,
+            NorthEast => x > 0.0 && y < 0.0,
+            NorthWest => x < 0.0 && y < 0.0,
+            SouthEast => x > 0.0 && y > 0.0,
+            SouthWest => x < 0.0 && y > 0.0,
+        }
+    }
+
+    /// The direction pointing the other way, such as `South` for `North`.
+    pub fn opposite(&self) -> Self {
+        use Direction::*;
+        match self {
+            South => North,
+            North => South,
+            East => West,
+            West => East,
+            NorthEast => SouthWest,
+            NorthWest => SouthEast,
+            SouthEast => NorthWest,
+            SouthWest => NorthEast,
+        }
+    }
+
+    /// The direction mirrored horizontally, such as `West` for `East`.
+    ///
+    /// `North` and `South` are unchanged.
+    pub fn mirrored(&self) -> Self {
+        use Direction::*;
+        match self {
+            East => West,
+            West => East,
+            NorthEast => NorthWest,
+            NorthWest => NorthEast,
+            SouthEast => SouthWest,
+            SouthWest => SouthEast,
+            North | South => *self,
+        }
+    }
+
+    /// The axis this direction moves along, `None` for diagonals.
+    pub fn axis(&self) -> Option<DirectionAxis> {
+        use Direction::*;
+        match self {
+            East | West => Some(DirectionAxis::Horizontal),
+            North | South => Some(DirectionAxis::Vertical),
+            NorthEast | NorthWest | SouthEast | SouthWest => None,
+        }
+    }
+
+    /// Whether this is one of `NorthEast`, `NorthWest`, `SouthEast` or `SouthWest`.
+    pub fn is_diagonal(&self) -> bool {
+        use Direction::*;
+        matches!(self, NorthEast | NorthWest | SouthEast | SouthWest)
+    }
+}
+
+/// Returns the next or previous element of `siblings` from `focused`
+/// based on `direction`.
+///
+/// Cycles if `cycles`, `None` if `focused` is not in `siblings`,
+/// `direction` is a dead end or `focused` is the only element of `siblings`.
+///
+/// `siblings` should only contain the elements that can be focused,
+/// the result is then always the closest one in `direction`.
+pub fn resolve_scope<'a, T: PartialEq>(
+    focused: &T,
+    direction: ScopeDirection,
+    cycles: bool,
+    siblings: &'a [T],
+) -> Option<&'a T> {
+    let focused_index = siblings.iter().position(|e| e == focused)?;
+    let new_index = resolve_index(focused_index, cycles, direction, siblings.len() - 1)?;
+    // Cycling in a single element list would go back to `focused`.
+    (new_index != focused_index).then(|| &siblings[new_index])
+}
+
+/// Cycle through a [scoped menu](crate::menu::MenuSetting::scope)
+/// according to menu settings.
+///
+/// Returns the index of the element to focus according to `direction`.
+/// Cycles if `cycles` and goes over `max_value` or goes bellow 0.
+/// `None` if the direction is a dead end.
+pub fn resolve_index(
+    from: usize,
+    cycles: bool,
+    direction: ScopeDirection,
+    max_value: usize,
+) -> Option<usize> {
+    use ScopeDirection::*;
+    match (direction, from) {
+        (Previous, 0) => cycles.then_some(max_value),
+        (Previous, from) => Some(from - 1),
+        (Next, from) if from == max_value => cycles.then_some(0),
+        (Next, from) => Some(from + 1),
+    }
+}
+
+/// Remove all mutually identical elements at the end of `v1` and `v2`.
+///
+/// This keeps at least one element in each vector, so if one is the tail
+/// of the other, it is left untouched.
+pub fn trim_common_tail<T: PartialEq>(v1: &mut NonEmpty<T>, v2: &mut NonEmpty<T>) {
+    let mut i1 = v1.len().get() - 1;
+    let mut i2 = v2.len().get() - 1;
+    loop {
+        if v1[i1] != v2[i2] {
+            // unwraps: any usize + 1 (saturating) is NonZero
+            let l1 = NonZeroUsize::new(i1.saturating_add(1)).unwrap();
+            let l2 = NonZeroUsize::new(i2.saturating_add(1)).unwrap();
+            v1.truncate(l1);
+            v2.truncate(l2);
+            return;
+        } else if i1 != 0 && i2 != 0 {
+            i1 -= 1;
+            i2 -= 1;
+        } else {
+            // There is no changes to be made to the input vectors
+            return;
+        }
+    }
+}
+
+/// Index of the first of `names` starting with `prefix`, ignoring case,
+/// searching from `from` and wrapping around the end of `names`.
+///
+/// When `prefix` is a single repeated character, such as `"aaa"`, this
+/// instead cycles through the names starting with that character, beginning
+/// right after `from`. This is how file lists in most OSes behave.
+///
+/// `None` if no names match or `prefix` is empty.
+pub fn type_ahead_index(from: Option<usize>, prefix: &str, names: &[&str]) -> Option<usize> {
+    let prefix = prefix.to_lowercase();
+    let mut chars = prefix.chars();
+    let first = chars.next()?;
+    let (prefix, skip) = if chars.all(|c| c == first) {
+        (first.to_string(), 1)
+    } else {
+        (prefix, 0)
+    };
+    let start = from.map_or(0, |i| i + skip);
+    let len = names.len();
+    (0..len)
+        .map(|i| (start + i) % len)
+        .find(|i| names[*i].to_lowercase().starts_with(&prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use non_empty_vec::ne_vec;
+
+    #[test]
+    fn test_direction_helpers() {
+        use Direction::*;
+        let all = [
+            South, North, East, West, NorthEast, NorthWest, SouthEast, SouthWest,
+        ];
+        for direction in all {
+            assert_ne!(direction.opposite(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().axis(), direction.axis());
+            assert_eq!(direction.axis().is_none(), direction.is_diagonal());
+        }
+        assert_eq!(East.axis(), Some(DirectionAxis::Horizontal));
+        assert_eq!(North.axis(), Some(DirectionAxis::Vertical));
+        assert_eq!(ScopeDirection::Next.reverse(), ScopeDirection::Previous);
+        assert_eq!(ScopeDirection::Previous.reverse(), ScopeDirection::Next);
+    }
+
+    #[test]
+    fn test_trim_common_tail() {
+        let mut v1 = ne_vec![1, 2, 3, 4, 5, 6, 7];
+        let mut v2 = ne_vec![3, 2, 1, 4, 5, 6, 7];
+        trim_common_tail(&mut v1, &mut v2);
+        assert_eq!(v1, ne_vec![1, 2, 3]);
+        assert_eq!(v2, ne_vec![3, 2, 1]);
+
+        let mut v1 = ne_vec![1, 2];
+        let mut v2 = ne_vec![3, 4];
+        trim_common_tail(&mut v1, &mut v2);
+        assert_eq!(v1, ne_vec![1, 2]);
+        assert_eq!(v2, ne_vec![3, 4]);
+    }
+
+    #[test]
+    fn test_resolve_index() {
+        use ScopeDirection::{Next, Previous};
+        assert_eq!(resolve_index(0, false, Previous, 3), None);
+        assert_eq!(resolve_index(0, true, Previous, 3), Some(3));
+        assert_eq!(resolve_index(3, false, Next, 3), None);
+        assert_eq!(resolve_index(3, true, Next, 3), Some(0));
+        assert_eq!(resolve_index(1, false, Next, 3), Some(2));
+        assert_eq!(resolve_index(1, false, Previous, 3), Some(0));
+    }
+
+    #[test]
+    fn test_resolve_scope() {
+        use ScopeDirection::{Next, Previous};
+        let siblings = ['a', 'b', 'c'];
+        assert_eq!(resolve_scope(&'b', Next, false, &siblings), Some(&'c'));
+        assert_eq!(resolve_scope(&'a', Previous, true, &siblings), Some(&'c'));
+        assert_eq!(resolve_scope(&'c', Next, false, &siblings), None);
+        assert_eq!(resolve_scope(&'z', Next, true, &siblings), None);
+        assert_eq!(resolve_scope(&'a', Next, true, &['a']), None);
+        assert_eq!(resolve_scope(&'a', Previous, true, &['a']), None);
+        assert_eq!(resolve_scope(&'b', Next, true, &['a', 'b']), Some(&'a'));
+    }
+
+    #[test]
+    fn test_contains_offset() {
+        use Direction::*;
+        assert!(North.contains_offset(0.0, -1.0));
+        assert!(South.contains_offset(0.0, 1.0));
+        assert!(East.contains_offset(1.0, 0.0));
+        assert!(West.contains_offset(-1.0, 0.0));
+        assert!(East.contains_offset(2.0, 1.0));
+        assert!(!East.contains_offset(1.0, 2.0));
+        assert!(!North.contains_offset(0.0, 0.0));
+        assert!(NorthEast.contains_offset(1.0, -2.0));
+        assert!(SouthWest.contains_offset(-1.0, 1.0));
+        assert!(!NorthWest.contains_offset(1.0, -1.0));
+        assert!(!SouthEast.contains_offset(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_type_ahead_index() {
+        let names = ["Apple", "apricot", "Banana", "Blueberry", "cherry"];
+        assert_eq!(type_ahead_index(None, "b", &names), Some(2));
+        assert_eq!(type_ahead_index(Some(0), "a", &names), Some(1));
+        assert_eq!(type_ahead_index(Some(1), "a", &names), Some(0));
+        assert_eq!(type_ahead_index(Some(2), "bb", &names), Some(3));
+        assert_eq!(type_ahead_index(Some(0), "ap", &names), Some(0));
+        assert_eq!(type_ahead_index(Some(0), "apr", &names), Some(1));
+        assert_eq!(type_ahead_index(Some(4), "BL", &names), Some(3));
+        assert_eq!(type_ahead_index(Some(0), "z", &names), None);
+        assert_eq!(type_ahead_index(Some(0), "", &names), None);
+        assert_eq!(type_ahead_index(None, "a", &[]), None);
+    }
+}
@@ -43,34 +43,55 @@ pub mod components;
 #[cfg(feature = "cuicui_dsl")]
 mod dsl;
 pub mod events;
+pub mod generic;
 mod marker;
 pub mod menu;
 mod named;
+mod nav_tree;
 mod resolve;
+#[cfg(feature = "bevy_reflect")]
+mod scene;
 pub mod systems;
+mod validate;
 
 use std::marker::PhantomData;
 
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::ecs::system::{SystemParam, SystemParamItem};
 use bevy::prelude::*;
 
+#[doc(hidden)]
+pub use nav_tree::add_child as __nav_tree_add_child;
 pub use non_empty_vec::NonEmpty;
+#[cfg(feature = "bevy_reflect")]
+pub use scene::{restore_nav, snapshot_nav};
+pub use validate::{validate_nav_tree, NavTreeIssue};
 
 #[cfg(feature = "bevy_ui")]
 use resolve::UiProjectionQuery;
 
 /// Default imports for `bevy_ui_navigation`.
 pub mod prelude {
+    pub use crate::commands::{RefreshMenu, SetActiveChild};
     #[cfg(feature = "cuicui_dsl")]
     pub use crate::dsl::NavigationDsl;
-    pub use crate::events::{NavEvent, NavEventReaderExt, NavRequest};
-    pub use crate::menu::{MenuBuilder, MenuSetting};
+    pub use crate::events::{
+        ActiveTrail, CoalesceNavRequests, FrameNavSummary, InputSource, NavEvent,
+        NavEventReaderExt, NavIdle, NavRequest, UnlockWith,
+    };
+    pub use crate::menu::{MenuBuilder, MenuMember, MenuSetting, NavStrategy};
     pub use crate::resolve::{
-        FocusAction, FocusState, Focusable, Focused, MenuNavigationStrategy, NavLock,
+        ActionSources, ActionTarget, ActiveChildren, FocusAction, FocusId, FocusMagnet, FocusState,
+        Focusable, FocusableFilter, Focused, InitialFocus, MenuNavigationStrategy,
+        NavDirectionality, NavFilter, NavLinks, NavLock, NavScopeStack, PointerOnly, Selectable,
+        Selected, Selection, Toggleable,
     };
-    pub use crate::NavRequestSystem;
+    pub use crate::systems::{AutoFocusableExt, ConditionalFocusableExt};
+    #[cfg(feature = "pointer_focus")]
+    pub use crate::systems::{ClickActivates, Hovered, Pressed};
     #[cfg(feature = "bevy_ui")]
     pub use crate::{DefaultNavigationPlugins, NavigationPlugin};
+    pub use crate::{FocusableCondition, NavRequestSystem};
 }
 /// Utilities to mark focusables within a menu with a specific component.
 pub mod mark {
@@ -80,8 +101,11 @@ pub mod mark {
 /// Types useful to define your own custom navigation inputs.
 pub mod custom {
     #[cfg(feature = "bevy_ui")]
-    pub use crate::resolve::UiProjectionQuery;
-    pub use crate::resolve::{Rect, ScreenBoundaries};
+    pub use crate::resolve::{
+        FallbackFocusPoint, FocusNearest, FocusNeighbors, NavCamera, SkipClipped, SkipOffscreen,
+        UiGridQuery, UiProjectionQuery,
+    };
+    pub use crate::resolve::{NavOrder, Rect, ScreenBoundaries};
     pub use crate::GenericNavigationPlugin;
 }
 
@@ -92,6 +116,10 @@ pub mod custom {
 /// `NavMarkerPropagationPlugin<T>` to your bevy app. It is possible to add any
 /// amount of `NavMarkerPropagationPlugin<T>` for as many `T` you need to
 /// propagate through the menu system.
+///
+/// When a [`Focusable`](prelude::Focusable) is moved out of a [`mark::NavMarker`] menu,
+/// the `T` component the plugin added to it is removed. `T` components you
+/// added yourself are left untouched.
 pub struct NavMarkerPropagationPlugin<T>(PhantomData<T>);
 impl<T> NavMarkerPropagationPlugin<T> {
     #[allow(clippy::new_without_default)]
@@ -108,6 +136,7 @@ impl<T: 'static + Sync + Send + Component + Clone> Plugin for NavMarkerPropagati
             (
                 marker::mark_new_menus::<T>,
                 marker::mark_new_focusables::<T>,
+                marker::mark_moved_focusables::<T>,
             ),
         );
     }
@@ -163,6 +192,16 @@ impl<T: 'static + Sync + Send + Component + Clone> Plugin for NavMarkerPropagati
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SystemSet)]
 pub struct NavRequestSystem;
 
+/// The label of the systems blocking and unblocking [`Focusable`]s
+/// added with [`ConditionalFocusableExt::add_conditional_focusable`].
+///
+/// It runs before the [`NavRequestSystem`].
+///
+/// [`Focusable`]: prelude::Focusable
+/// [`ConditionalFocusableExt::add_conditional_focusable`]: prelude::ConditionalFocusableExt::add_conditional_focusable
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SystemSet)]
+pub struct FocusableCondition;
+
 /// The navigation plugin.
 ///
 /// Add it to your app with `.add_plugins(NavigationPlugin::new())` and send
@@ -176,20 +215,86 @@ pub struct NavRequestSystem;
 /// The `STGY` type parameter might seem complicated, but all you have to do
 /// is for your type to implement [`SystemParam`] and [`MenuNavigationStrategy`].
 ///
+/// # Schedules
+///
+/// By default, menus are built in `PreUpdate` and [`NavRequest`]s are handled
+/// in `Update`. Use [`GenericNavigationPlugin::in_schedule`] to run all
+/// navigation systems in a different schedule, such as `FixedUpdate`.
+///
 /// [`MenuNavigationStrategy`]: resolve::MenuNavigationStrategy
 /// [`Focusable`]: prelude::Focusable
 /// [`NavRequest`]: prelude::NavRequest
-#[derive(Default)]
-pub struct GenericNavigationPlugin<STGY>(PhantomData<fn() -> STGY>);
+pub struct GenericNavigationPlugin<STGY> {
+    schedule: Option<InternedScheduleLabel>,
+    emit_no_changes: bool,
+    no_focusable_grace: u32,
+    _strategy: PhantomData<fn() -> STGY>,
+}
 #[cfg(feature = "bevy_ui")]
 /// A default [`GenericNavigationPlugin`] for `bevy_ui`.
 pub type NavigationPlugin<'w, 's> = GenericNavigationPlugin<UiProjectionQuery<'w, 's>>;
 
+impl<STGY> Default for GenericNavigationPlugin<STGY> {
+    fn default() -> Self {
+        Self {
+            schedule: None,
+            emit_no_changes: true,
+            no_focusable_grace: 120,
+            _strategy: PhantomData,
+        }
+    }
+}
 impl<STGY: resolve::MenuNavigationStrategy> GenericNavigationPlugin<STGY> {
     /// Create a new [`GenericNavigationPlugin`] with the provided `STGY`,
     /// see also [`resolve::MenuNavigationStrategy`].
     pub fn new() -> Self {
-        Self(PhantomData)
+        Self::default()
+    }
+}
+impl<STGY> GenericNavigationPlugin<STGY> {
+    /// Run all the navigation systems in `schedule`, rather than
+    /// `PreUpdate` and `Update`.
+    ///
+    /// [`NavRequestSystem`] and [`FocusableCondition`] are configured
+    /// in `schedule`, so make sure to order your input and UI systems
+    /// relative to them in `schedule` too.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = Some(schedule.intern());
+        self
+    }
+
+    /// Whether to send [`NavEvent::NoChanges`] when a [`NavRequest`]
+    /// doesn't change the focus, `true` by default.
+    ///
+    /// With `false`, [`NavEvent::NoChanges`] is still sent for
+    /// [`NavRequest::Action`], so that [`NavEventReaderExt::nav_iter`]'s
+    /// `activated` methods keep working. This reduces event traffic,
+    /// but you can't tell anymore when other requests fail, for example
+    /// to play a "bump" sound when moving into a wall.
+    ///
+    /// [`NavEvent::NoChanges`]: prelude::NavEvent::NoChanges
+    /// [`NavRequest`]: prelude::NavRequest
+    /// [`NavRequest::Action`]: prelude::NavRequest::Action
+    /// [`NavEventReaderExt::nav_iter`]: prelude::NavEventReaderExt::nav_iter
+    pub fn emit_no_changes(mut self, emit: bool) -> Self {
+        self.emit_no_changes = emit;
+        self
+    }
+
+    /// For how many frames without any [`Focusable`] to tolerate
+    /// [`NavRequest`]s before warning about them, 120 by default.
+    ///
+    /// Input often arrives before the UI is spawned, for example
+    /// during a loading screen. [`NavRequest`]s do nothing without
+    /// [`Focusable`]s, this is only logged at the `debug` level
+    /// for the first `frames` frames without [`Focusable`]s, and
+    /// as a warning afterward.
+    ///
+    /// [`Focusable`]: prelude::Focusable
+    /// [`NavRequest`]: prelude::NavRequest
+    pub fn no_focusable_grace(mut self, frames: u32) -> Self {
+        self.no_focusable_grace = frames;
+        self
     }
 }
 impl<STGY: SystemParam + 'static> Plugin for GenericNavigationPlugin<STGY>
@@ -199,7 +304,9 @@ where
     fn build(&self, app: &mut App) {
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<menu::MenuBuilder>()
+            .register_type::<menu::MenuMember>()
             .register_type::<menu::MenuSetting>()
+            .register_type::<menu::NavStrategy>()
             .register_type::<resolve::Focusable>()
             .register_type::<resolve::FocusAction>()
             .register_type::<resolve::FocusState>()
@@ -207,24 +314,97 @@ where
             .register_type::<resolve::NavLock>()
             .register_type::<resolve::Rect>()
             .register_type::<resolve::ScreenBoundaries>()
+            .register_type::<resolve::Toggleable>()
+            .register_type::<resolve::Selectable>()
+            .register_type::<resolve::Selected>()
+            .register_type::<resolve::ActionSources>()
+            .register_type::<resolve::ActionTarget>()
+            .register_type::<resolve::FocusId>()
+            .register_type::<resolve::FocusMagnet>()
+            .register_type::<resolve::InitialFocus>()
+            .register_type::<resolve::NavLinks>()
+            .register_type::<resolve::PointerOnly>()
+            .register_type::<resolve::NavDirectionality>()
             .register_type::<resolve::TreeMenu>()
+            .register_type::<events::FrameNavSummary>()
+            .register_type::<events::ActiveTrail>()
+            .register_type::<events::NavIdle>()
+            .register_type::<events::CoalesceNavRequests>()
+            .register_type::<events::UnlockWith>()
             .register_type::<systems::InputMapping>();
+        #[cfg(all(feature = "bevy_reflect", feature = "bevy_ui"))]
+        app.register_type::<resolve::FallbackFocusPoint>()
+            .register_type::<resolve::SkipClipped>()
+            .register_type::<resolve::SkipOffscreen>()
+            .register_type::<resolve::NavCamera>();
 
+        let (sender, receiver) = events::nav_request_channel();
         app.add_event::<events::NavRequest>()
             .add_event::<events::NavEvent>()
+            .insert_resource(sender)
+            .insert_resource(receiver)
             .insert_resource(resolve::NavLock::new())
+            .insert_resource(resolve::NavSettings {
+                emit_no_changes: self.emit_no_changes,
+                no_focusable_grace: self.no_focusable_grace,
+                menu_schedule: self.schedule.unwrap_or(PreUpdate.intern()),
+            })
+            .init_resource::<resolve::FocusableFilter>();
+
+        let schedule = self.schedule.unwrap_or(Update.intern());
+        let build_menus = (
+            events::drain_nav_request_channel,
+            // Nothing to build in apps without menus, such as with only rootless focusables.
+            (named::resolve_named_menus, resolve::insert_tree_menus)
+                .chain()
+                .run_if(any_with_component::<menu::MenuBuilder>()),
+        );
+        #[cfg(debug_assertions)]
+        let build_menus = (
+            build_menus,
+            resolve::warn_multiple_prioritized.after(resolve::insert_tree_menus),
+        );
+        app.configure_sets(schedule, FocusableCondition.before(NavRequestSystem))
             .add_systems(
-                Update,
+                schedule,
                 (
-                    (resolve::set_first_focused, resolve::consistent_menu),
+                    (resolve::set_first_focused::<STGY>, resolve::consistent_menu)
+                        .after(FocusableCondition),
+                    resolve::update_scope_stack.run_if(resource_exists::<resolve::NavScopeStack>()),
                     resolve::listen_nav_requests::<STGY>.in_set(NavRequestSystem),
                 )
                     .chain(),
-            )
-            .add_systems(
-                PreUpdate,
-                (named::resolve_named_menus, resolve::insert_tree_menus).chain(),
             );
+        app.add_systems(schedule, resolve::emit_lock_changes.after(NavRequestSystem));
+        app.add_systems(
+            schedule,
+            events::update_nav_idle
+                .run_if(resource_exists::<events::NavIdle>())
+                .after(NavRequestSystem),
+        );
+        app.add_systems(
+            schedule,
+            resolve::expire_focus_magnets
+                .run_if(any_with_component::<resolve::FocusMagnet>())
+                .after(NavRequestSystem),
+        );
+        #[cfg(feature = "bevy_ui")]
+        app.add_systems(
+            schedule,
+            (resolve::update_viewport, resolve::refocus_offscreen)
+                .chain()
+                .run_if(resource_exists::<resolve::SkipOffscreen>())
+                .before(NavRequestSystem),
+        );
+        match self.schedule {
+            Some(schedule) => app.add_systems(
+                schedule,
+                build_menus
+                    .before(FocusableCondition)
+                    .before(NavRequestSystem),
+            ),
+            None => app.add_systems(PreUpdate, build_menus),
+        };
     }
 }
 
@@ -281,12 +461,18 @@ mod test {
     enum SpawnHierarchy {
         Rootless(SpawnRootless),
         Menu(SpawnMenu),
+        Roots(Vec<SpawnMenu>),
     }
     impl SpawnHierarchy {
         fn spawn(self, world: &mut World) {
             match self {
                 Self::Rootless(menu) => menu.spawn(world),
                 Self::Menu(menu) => menu.spawn(&mut world.spawn_empty()),
+                Self::Roots(menus) => {
+                    for menu in menus.into_iter() {
+                        menu.spawn(&mut world.spawn_empty());
+                    }
+                }
             };
         }
     }
@@ -356,6 +542,9 @@ mod test {
     ///   A hierarchy of focusable components with a root menu.
     /// - `spawn_hierarchy!(@rootless [ <focus_kind> , ...] )`:
     ///   A hierarchy of focusable components **without** a root menu.
+    /// - `spawn_hierarchy!(@roots [ "Root Name" [ <focus_kind> , ...] , ...] )`:
+    ///   Several disjoint hierarchies, each with its own root menu
+    ///   marked with the `Name::new("Root Name")` component.
     /// - `<focus_kind>` is one of the following:
     ///   - `focusable("Custom")`: a focusable with the `Name::new("Custom")` component
     ///   - `focusable_to("Custom" [ <focus_kind> , ...] )`:
@@ -372,6 +561,11 @@ mod test {
                 )* ],
             })
         );
+        ( @roots [ $( $root_name:literal [ $( $root_elems:tt )* ] ),* $(,)? ] ) => (
+            SpawnHierarchy::Roots(vec![ $(
+                spawn_hierarchy!(@menu $root_name, $( $root_elems )*),
+            )* ])
+        );
         ( @menu $name:expr, $( $elem_name:ident $elem_args:tt ),* $(,)? ) => (
             SpawnMenu {
                 name: $name,
@@ -416,7 +610,7 @@ mod test {
     /// There is nothing beside that that would prevent converting this into a function.
     macro_rules! assert_expected_focus_change {
         ($app:expr, $events:expr, $expected_from:expr, $expected_to:expr $(,)?) => {
-            if let [NavEvent::FocusChanged { to, from }] = $events {
+            if let [NavEvent::FocusChanged { to, from, .. }] = $events {
                 let actual_from = $app.name_list(&*from);
                 assert_eq!(&*actual_from, $expected_from);
 
@@ -431,17 +625,29 @@ mod test {
         };
     }
 
-    // A navigation strategy that does nothing, useful for testing.
+    // A navigation strategy that only moves between focusables with a `Transform`,
+    // to the closest one in the requested direction. Useful for testing.
     #[derive(SystemParam)]
     struct MockNavigationStrategy<'w, 's> {
-        #[system_param(ignore)]
-        _f: PhantomData<fn() -> (&'w (), &'s ())>,
+        transforms: Query<'w, 's, &'static Transform>,
     }
     // Just to make the next `impl` block shorter, unused otherwise.
     use events::Direction as D;
     impl<'w, 's> MenuNavigationStrategy for MockNavigationStrategy<'w, 's> {
-        fn resolve_2d<'a>(&self, _: Entity, _: D, _: bool, _: &'a [Entity]) -> Option<&'a Entity> {
-            None
+        fn resolve_2d<'a>(
+            &self,
+            from: Entity,
+            dir: D,
+            _: bool,
+            s: &'a [Entity],
+        ) -> Option<&'a Entity> {
+            use bevy::utils::FloatOrd;
+            let pos = |e: Entity| Some(self.transforms.get(e).ok()?.translation.truncate());
+            let from_pos = pos(from)?;
+            let in_dir = |e: &&Entity| pos(**e).is_some_and(|p| dir.is_in(from_pos, p));
+            s.iter()
+                .filter(in_dir)
+                .min_by_key(|e| FloatOrd(pos(**e).unwrap().distance_squared(from_pos)))
         }
     }
     fn receive_events<E: Event + Clone>(world: &World) -> Vec<E> {
@@ -449,6 +655,15 @@ mod test {
         events.iter_current_update_events().cloned().collect()
     }
 
+    /// The entity with the `entity_name` [`Name`], panics if there is none.
+    pub(crate) fn entity_named(world: &mut World, entity_name: &str) -> Entity {
+        let mut query = world.query::<(Entity, &Name)>();
+        query
+            .iter(world)
+            .find_map(|(e, name)| (&**name == entity_name).then(|| e))
+            .unwrap()
+    }
+
     /// Wrapper around `App` to make it easier to test the navigation systems.
     struct NavEcsMock {
         app: App,
@@ -486,12 +701,19 @@ mod test {
 
             Self { app }
         }
+        fn entity_named(&mut self, entity_name: &str) -> Entity {
+            entity_named(&mut self.app.world, entity_name)
+        }
+        /// Set the `Transform` of the named entities to the given `x` positions.
+        fn place(&mut self, positions: &[(&str, f32)]) {
+            for (name, x) in positions {
+                let entity = self.entity_named(name);
+                let transform = Transform::from_xyz(*x, 0.0, 0.0);
+                self.app.world.entity_mut(entity).insert(transform);
+            }
+        }
         fn run_focus_on(&mut self, entity_name: &str) -> Vec<NavEvent> {
-            let mut query = self.app.world.query::<(Entity, &Name)>();
-            let requested = query
-                .iter(&self.app.world)
-                .find_map(|(e, name)| (&**name == entity_name).then(|| e))
-                .unwrap();
+            let requested = self.entity_named(entity_name);
             self.app.world.send_event(NavRequest::FocusOn(requested));
             self.app.update();
             receive_events(&mut self.app.world)
@@ -612,6 +834,3014 @@ mod test {
         );
     }
 
+    #[test]
+    fn frame_summary_tracks_net_focus() {
+        use crate::events::FrameNavSummary;
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        app.app.init_resource::<FrameNavSummary>();
+        app.run_focus_on("Left");
+        let left = app.entity_named("Left");
+        let summary = *app.app.world.resource::<FrameNavSummary>();
+        assert_eq!(summary.final_focus, Some(left));
+        assert!(summary.changed);
+
+        app.app.update();
+        let summary = *app.app.world.resource::<FrameNavSummary>();
+        assert_eq!(summary.final_focus, Some(left));
+        assert!(!summary.changed);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn history_records_and_replays() {
+        use crate::events::NavHistory;
+        let hierarchy = || {
+            spawn_hierarchy![
+                prioritized("Initial"),
+                focusable_to("Left" [
+                    focusable("LTop"),
+                    focusable("LBottom"),
+                ]),
+            ]
+        };
+        let mut app = NavEcsMock::new(hierarchy());
+        app.app.insert_resource(NavHistory::new(2));
+        app.run_focus_on("LBottom");
+        app.run_request(NavRequest::Cancel);
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "LBottom");
+
+        // Only the last 2 requests are kept
+        let history = app.app.world.resource::<NavHistory>();
+        let requests: Vec<_> = history.requests().collect();
+        assert_eq!(requests, [NavRequest::Cancel, NavRequest::Action]);
+        let cancel = history.entries().next().unwrap();
+        assert!(matches!(cancel.events[..], [NavEvent::FocusChanged { .. }]));
+        assert_eq!(history.dump().lines().count(), 4);
+
+        // Replaying the requests on the same initial state gives the same focus
+        let mut replay = NavEcsMock::new(hierarchy());
+        replay.run_focus_on("LBottom");
+        for request in requests {
+            replay.run_request(request);
+        }
+        assert_eq!(replay.currently_focused(), "LBottom");
+    }
+
+    #[test]
+    fn secondary_actions() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Sword"),
+            focusable_to("Bag" [
+                focusable("Potion"),
+            ]),
+        ]);
+        let sword = app.entity_named("Sword");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(events[..], [ref event] if event.is_activated(sword)));
+        let events = app.run_request(NavRequest::SecondaryAction);
+        assert!(matches!(events[..], [NavEvent::SecondaryActivated(e)] if e == sword));
+        let events = app.run_request(NavRequest::TertiaryAction);
+        assert!(matches!(events[..], [NavEvent::TertiaryActivated(e)] if e == sword));
+        assert_eq!(app.currently_focused(), "Sword");
+
+        // Secondary actions do not enter submenus
+        app.run_focus_on("Bag");
+        let bag = app.entity_named("Bag");
+        let events = app.run_request(NavRequest::SecondaryAction);
+        assert!(matches!(events[..], [NavEvent::SecondaryActivated(e)] if e == bag));
+        assert_eq!(app.currently_focused(), "Bag");
+    }
+
+    #[test]
+    fn coalesce_requests() {
+        use events::Direction::{East, West};
+
+        let hierarchy = || {
+            spawn_hierarchy!(@rootless [
+                prioritized("Initial"),
+                focusable("One"),
+                focusable("Two"),
+            ])
+        };
+        let send_moves = |app: &mut NavEcsMock| {
+            app.place(&[("Initial", 0.0), ("One", 100.0), ("Two", 200.0)]);
+            for _ in 0..4 {
+                app.app.world.send_event(NavRequest::Move(East));
+            }
+            app.run_request(NavRequest::Move(East))
+        };
+        let mut app = NavEcsMock::new(hierarchy());
+        send_moves(&mut app);
+        assert_eq!(app.currently_focused(), "Two");
+
+        let mut app = NavEcsMock::new(hierarchy());
+        app.app.init_resource::<CoalesceNavRequests>();
+        let events = send_moves(&mut app);
+        assert!(matches!(events[..], [NavEvent::FocusChanged { .. }]));
+        assert_eq!(app.currently_focused(), "One");
+
+        // Only consecutive identical requests are coalesced.
+        app.app.world.send_event(NavRequest::Move(East));
+        app.app.world.send_event(NavRequest::Move(West));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Two");
+
+        // Requests are coalesced in processing order, `FocusOn` comes first.
+        let initial = app.entity_named("Initial");
+        app.app.world.send_event(NavRequest::Move(East));
+        app.app.world.send_event(NavRequest::FocusOn(initial));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "One");
+    }
+
+    #[test]
+    fn nav_idle() {
+        use crate::events::NavIdle;
+        use events::Direction::{East, West};
+        use std::time::Duration;
+
+        let mut app =
+            NavEcsMock::new(spawn_hierarchy![prioritized("Initial"), focusable("Right"),]);
+        app.place(&[("Initial", 0.0), ("Right", 100.0)]);
+        app.app.init_resource::<NavIdle>().init_resource::<Time>();
+        let frame = Duration::from_millis(100);
+        app.app.world.resource_mut::<Time>().advance_by(frame);
+        let since = |app: &NavEcsMock| app.app.world.resource::<NavIdle>().since;
+
+        app.app.update();
+        app.app.update();
+        assert_eq!(since(&app), frame * 2);
+        assert!(app.app.world.resource::<NavIdle>().is_idle_for(frame * 2));
+
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(since(&app), Duration::ZERO);
+        app.app.update();
+        assert_eq!(since(&app), frame);
+
+        // Moving into a wall doesn't change focus.
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(since(&app), frame * 2);
+        app.app.world.resource_mut::<NavIdle>().reset_on_any_request = true;
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(since(&app), Duration::ZERO);
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
+    #[test]
+    fn focusables_outside_menus() {
+        use events::Direction::{East, West};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![prioritized("M1"), focusable("M2"),]);
+        app.place(&[("M1", 50.0)]);
+        let world = &mut app.app.world;
+        for (name, x) in [("A", 0.0), ("B", 100.0), ("C", 200.0)] {
+            let transform = Transform::from_xyz(x, 0.0, 0.0);
+            world.spawn((Focusable::new(), Name::new(name), transform));
+        }
+        app.app.update();
+
+        app.run_focus_on("A");
+        // "M1" is closer, but in a menu.
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "B");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "B");
+
+        // Focusables in menus don't move to focusables outside of them.
+        app.run_focus_on("M1");
+        let events = app.run_request(NavRequest::Move(West));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+    }
+
+    #[test]
+    fn initial_focus_outside_menus() {
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new());
+        let menu = (MenuSetting::new(), MenuBuilder::from_named("B"));
+        let menu = app.world.spawn(menu).id();
+        for name in ["B1", "B2"] {
+            let focusable = app.world.spawn((Focusable::new(), Name::new(name))).id();
+            app.world.entity_mut(menu).add_child(focusable);
+        }
+        // With a `Transform`, they come after the focusables of the menu in queries.
+        for name in ["A", "B", "C"] {
+            let transform = Transform::default();
+            app.world
+                .spawn((Focusable::new(), Name::new(name), transform));
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "A");
+    }
+
+    #[test]
+    fn nav_tree_macro() {
+        use crate::nav_tree;
+
+        let setup = |mut commands: Commands| {
+            nav_tree!(commands,
+                // Declared before the focusable it is reachable from.
+                options_menu: menu(MenuSetting::new(), Name::new("Options Menu")) from options [
+                    volume: focusable(Name::new("Volume")),
+                    back: focusable(Name::new("Back")),
+                ],
+                main: menu(MenuSetting::new(), Name::new("Main")) [
+                    play: prioritized(Name::new("Play")),
+                    row: node(Name::new("Row")) [
+                        options: focusable(Name::new("Options")),
+                    ],
+                ],
+            );
+            commands.entity(back).insert(Focusable::cancel());
+            commands
+                .spawn(Name::new("Ui"))
+                .add_child(options_menu)
+                .add_child(main);
+        };
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new())
+            .add_systems(Startup, setup);
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "Play");
+        let [options, row] = ["Options", "Row"].map(|name| app.entity_named(name));
+        let parent = app.app.world.get::<Parent>(options).map(Parent::get);
+        assert_eq!(parent, Some(row));
+
+        app.run_request(NavRequest::FocusOn(options));
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "Volume");
+        app.run_focus_on("Back");
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "Options");
+    }
+
+    #[test]
+    fn next_and_prev_root() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@roots [
+            "Pink" [
+                prioritized("P1"),
+                focusable("P2"),
+            ],
+            "Blue" [
+                focusable("B1"),
+                focusable_to("B2" [
+                    focusable("B2 Inner"),
+                ]),
+            ],
+            "Green" [
+                focusable("G1"),
+            ],
+        ]));
+        assert_eq!(app.currently_focused(), "P1");
+        let events = app.run_request(NavRequest::NextRoot);
+        assert_expected_focus_change!(app, &events[..], ["P1"], ["B1"]);
+
+        app.run_focus_on("B2 Inner");
+        let events = app.run_request(NavRequest::NextRoot);
+        assert_expected_focus_change!(app, &events[..], ["B2 Inner", "B2"], ["G1"]);
+        app.run_request(NavRequest::NextRoot);
+        assert_eq!(app.currently_focused(), "P1");
+
+        app.run_request(NavRequest::PrevRoot);
+        assert_eq!(app.currently_focused(), "G1");
+        app.run_request(NavRequest::PrevRoot);
+        assert_eq!(app.currently_focused(), "B2");
+
+        let mut app =
+            NavEcsMock::new(spawn_hierarchy![prioritized("Initial"), focusable("Other"),]);
+        let events = app.run_request(NavRequest::NextRoot);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+    }
+
+    #[test]
+    fn pointer_only() {
+        use events::Direction::{East, West};
+        use events::InputSource::Pointer;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new());
+        let hierarchy = spawn_hierarchy![
+            focusable("Initial"),
+            prioritized("Close"),
+            focusable("Other"),
+        ];
+        hierarchy.spawn(&mut app.world);
+        let mut app = NavEcsMock { app };
+        app.place(&[("Initial", 0.0), ("Close", 100.0), ("Other", 200.0)]);
+        let close = app.entity_named("Close");
+        app.app.world.entity_mut(close).insert(PointerOnly);
+        app.app.update();
+        assert_eq!(app.currently_focused(), "Initial");
+
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Other");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "Initial");
+
+        // What `enable_click_request` sends on click.
+        app.app.world.send_event(NavRequest::FocusOn(close));
+        let events = app.run_request(NavRequest::ActionFrom(Pointer));
+        assert_eq!(app.currently_focused(), "Close");
+        let activated = |event: &NavEvent| {
+            matches!(event, NavEvent::NoChanges { from, request }
+                if request.is_action() && *from.first() == close)
+        };
+        assert!(events.iter().any(activated));
+    }
+
+    #[test]
+    fn engage_required() {
+        use events::Direction::{East, West};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Canvas" [
+                focusable("C1"),
+                focusable("C2"),
+            ]),
+        ]);
+        app.place(&[("C1", 0.0), ("C2", 100.0)]);
+        let canvas = app.entity_named("Canvas Menu");
+        let mut setting = app.app.world.get_mut::<MenuSetting>(canvas).unwrap();
+        setting.engage_required = true;
+        let engaged = |events: &[NavEvent], value: bool| {
+            matches!(events, [.., NavEvent::Engaged { menu, engaged }]
+                if *menu == canvas && *engaged == value)
+        };
+
+        app.run_focus_on("Canvas");
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "C1");
+        let events = app.run_request(NavRequest::Move(East));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "C1");
+
+        let events = app.run_request(NavRequest::Action);
+        assert!(engaged(&events, true));
+        assert_eq!(app.currently_focused(), "C1");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C2");
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert!(engaged(&events, false));
+        assert_eq!(app.currently_focused(), "C2");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "C2");
+        app.run_request(NavRequest::Cancel);
+        assert_eq!(app.currently_focused(), "Canvas");
+
+        // Leaving an engaged menu disengages it.
+        app.run_request(NavRequest::Action);
+        app.run_request(NavRequest::Action);
+        let events = app.run_focus_on("Initial");
+        assert!(engaged(&events, false));
+        app.run_focus_on("C2");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "C2");
+
+        // A click engages the menu and activates the clicked element.
+        let c2 = app.entity_named("C2");
+        let events = app.run_request(NavRequest::ActionFrom(InputSource::Pointer));
+        assert!(
+            matches!(&events[..], [NavEvent::Engaged { engaged: true, .. }, activated]
+                if activated.is_activated(c2)),
+            "{events:#?}"
+        );
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "C1");
+    }
+
+    #[test]
+    fn toggle_select() {
+        use bevy::ecs::system::SystemState;
+        use events::Direction::East;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Sword"),
+            focusable("Shield"),
+            focusable("Potion"),
+            focusable("Map"),
+        ]);
+        for (i, name) in ["Sword", "Shield", "Potion", "Map"].iter().enumerate() {
+            let entity = app.entity_named(name);
+            let transform = Transform::from_xyz(i as f32 * 100.0, 0.0, 0.0);
+            let mut entity = app.app.world.entity_mut(entity);
+            entity.insert(transform);
+            if *name != "Map" {
+                entity.insert(Selectable);
+            }
+        }
+        let selection = |app: &mut NavEcsMock| {
+            let mut state = SystemState::<(Selection, Query<&Name>)>::new(&mut app.app.world);
+            let (selection, names) = state.get(&app.app.world);
+            let mut names: Vec<_> = selection.iter().map(|e| names.get(e).unwrap()).collect();
+            names.sort();
+            names.iter().map(|n| n.to_string()).collect::<Vec<_>>()
+        };
+        let sword = app.entity_named("Sword");
+
+        let events = app.run_request(NavRequest::ToggleSelect);
+        assert!(matches!(
+            events[..],
+            [NavEvent::SelectionChanged { entity, selected: true }] if entity == sword
+        ));
+        app.run_request(NavRequest::Move(East));
+        app.run_request(NavRequest::Move(East));
+        app.run_request(NavRequest::ToggleSelect);
+        assert_eq!(app.currently_focused(), "Potion");
+        assert_eq!(selection(&mut app), ["Potion", "Sword"]);
+
+        app.run_request(NavRequest::Move(East));
+        let events = app.run_request(NavRequest::ToggleSelect);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(selection(&mut app), ["Potion", "Sword"]);
+
+        app.run_focus_on("Sword");
+        let events = app.run_request(NavRequest::ToggleSelect);
+        assert!(matches!(
+            events[..],
+            [NavEvent::SelectionChanged {
+                selected: false,
+                ..
+            }]
+        ));
+        assert_eq!(selection(&mut app), ["Potion"]);
+
+        // Toggling twice in a single frame is a no-op.
+        app.app.world.send_event(NavRequest::ToggleSelect);
+        app.run_request(NavRequest::ToggleSelect);
+        assert_eq!(selection(&mut app), ["Potion"]);
+    }
+
+    #[test]
+    fn forget_on_leave() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+        ]);
+        let left_menu = app.entity_named("Left Menu");
+        let mut setting = app.app.world.get_mut::<MenuSetting>(left_menu).unwrap();
+        setting.forget_on_leave = true;
+
+        app.run_focus_on("L2");
+        app.run_request(NavRequest::Cancel);
+        assert_eq!(app.currently_focused(), "Left");
+        assert_eq!(app.state_of("L2"), FocusState::Inert);
+        assert_eq!(app.state_of("L1"), FocusState::Prioritized);
+
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[test]
+    fn active_trail() {
+        use crate::events::ActiveTrail;
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable_to("LTop" [
+                    focusable("LTopForward"),
+                ]),
+            ]),
+        ]);
+        app.app.init_resource::<ActiveTrail>();
+        app.run_focus_on("Left");
+        app.run_request(NavRequest::Action);
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "LTopForward");
+        let trail = app.app.world.resource::<ActiveTrail>().0.clone();
+        assert_eq!(app.name_list(&trail), ["Left", "LTop", "LTopForward"]);
+
+        app.run_request(NavRequest::CancelToRoot);
+        let trail = app.app.world.resource::<ActiveTrail>().0.clone();
+        assert_eq!(app.name_list(&trail), ["Left"]);
+    }
+
+    #[test]
+    fn cancel_to_root() {
+        use crate::events::FrameNavSummary;
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+                focusable_to("LBottom" [
+                    focusable("LBottomForward"),
+                ]),
+            ]),
+        ]);
+        app.app.init_resource::<FrameNavSummary>();
+        app.run_focus_on("LBottomForward");
+        assert_eq!(app.app.world.resource::<FrameNavSummary>().menu_depth, 2);
+
+        let events = app.run_request(NavRequest::CancelToRoot);
+        assert_expected_focus_change!(
+            app,
+            &events[..],
+            ["LBottomForward", "LBottom", "Left"],
+            ["Left"],
+        );
+        assert_eq!(app.app.world.resource::<FrameNavSummary>().menu_depth, 0);
+
+        let events = app.run_request(NavRequest::CancelToRoot);
+        assert!(
+            matches!(
+                events[..],
+                [NavEvent::NoChanges {
+                    request: NavRequest::CancelToRoot,
+                    ..
+                }]
+            ),
+            "{events:#?}"
+        );
+    }
+
+    #[test]
+    fn tree_initialized() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+            ]),
+        ]);
+        let events = receive_events::<NavEvent>(&app.app.world);
+        let [NavEvent::InitiallyFocused(initial), NavEvent::TreeInitialized { path }] = &events[..]
+        else {
+            panic!("Expected InitiallyFocused then TreeInitialized, got: {events:#?}");
+        };
+        assert_eq!(app.name_list(&[*initial]), ["LTop"]);
+        assert_eq!(app.name_list(path), ["LTop", "Left"]);
+
+        // Only sent once, not when focus changes afterward.
+        let events = app.run_focus_on("Initial");
+        assert!(
+            !events
+                .iter()
+                .any(|e| matches!(e, NavEvent::TreeInitialized { .. })),
+            "{events:#?}"
+        );
+    }
+
+    #[test]
+    fn unmark_moved_focusable() {
+        #[derive(Component, Clone)]
+        struct Marked;
+
+        let mut app = App::new();
+        app.add_plugins((
+            GenericNavigationPlugin::<MockNavigationStrategy>::new(),
+            NavMarkerPropagationPlugin::<Marked>::new(),
+        ));
+        spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+                focusable("Own"),
+            ]),
+        ]
+        .spawn(&mut app.world);
+        let mut app = NavEcsMock { app };
+        let [root, initial, l1, l2, own, left_menu] =
+            ["Root", "Initial", "L1", "L2", "Own", "Left Menu"].map(|n| app.entity_named(n));
+        let world = &mut app.app.world;
+        world.entity_mut(left_menu).insert(mark::NavMarker(Marked));
+        world.entity_mut(own).insert(Marked);
+        app.app.update();
+        app.app.update();
+        let marked = |app: &NavEcsMock, entity| app.app.world.entity(entity).contains::<Marked>();
+        assert!(marked(&app, l1));
+        assert!(marked(&app, l2));
+
+        let world = &mut app.app.world;
+        world.entity_mut(l1).set_parent(root);
+        world.entity_mut(own).set_parent(root);
+        world.entity_mut(initial).set_parent(left_menu);
+        app.app.update();
+        assert!(!marked(&app, l1));
+        assert!(marked(&app, l2));
+        assert!(marked(&app, initial));
+        // The user's own marker is kept.
+        assert!(marked(&app, own));
+
+        // Moving a non-focusable ancestor updates its focusables.
+        let panel = app.app.world.spawn(Name::new("Panel")).id();
+        app.app.world.entity_mut(panel).set_parent(left_menu);
+        app.app.world.entity_mut(l2).set_parent(panel);
+        app.app.update();
+        assert!(marked(&app, l2));
+        app.app.world.entity_mut(panel).set_parent(root);
+        app.app.update();
+        assert!(!marked(&app, l2));
+
+        app.app.world.entity_mut(initial).remove_parent();
+        app.app.update();
+        assert!(!marked(&app, initial));
+    }
+
+    #[test]
+    fn sequential_menu_strategy() {
+        use events::Direction::{East, North, South};
+        // Without `Transform`, `MockNavigationStrategy` can't move anywhere.
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("List" [
+                focusable("Item1"),
+                focusable("Item2"),
+                focusable("Item3"),
+            ]),
+        ]);
+        let list_menu = app.entity_named("List Menu");
+        app.app
+            .world
+            .entity_mut(list_menu)
+            .insert(NavStrategy::Sequential);
+
+        app.run_focus_on("Item1");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "Item2");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Item3");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "Item3");
+        app.run_request(NavRequest::Move(North));
+        assert_eq!(app.currently_focused(), "Item2");
+
+        // The root menu still uses the global strategy.
+        app.run_focus_on("Initial");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
+    #[test]
+    fn focus_lost_and_recovered() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ]);
+        app.run_focus_on("L1");
+        let [root, left_menu] = ["Root", "Left Menu"].map(|n| app.entity_named(n));
+        app.app.world.entity_mut(root).despawn_recursive();
+        app.app.world.entity_mut(left_menu).despawn_recursive();
+        app.app.update();
+        let events = receive_events::<NavEvent>(&app.app.world);
+        assert!(matches!(events[..], [NavEvent::FocusLost]), "{events:#?}");
+
+        // Only sent once.
+        app.app.update();
+        let events = receive_events::<NavEvent>(&app.app.world);
+        assert!(events.is_empty(), "{events:#?}");
+
+        let spawn = spawn_hierarchy![focusable("Respawned")];
+        spawn.spawn(&mut app.app.world);
+        app.app.update();
+        app.app.update();
+        assert_eq!(app.currently_focused(), "Respawned");
+    }
+
+    #[test]
+    fn peek_focus() {
+        use FocusState::{Active, Focused, Inert, Prioritized};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+            focusable("Right"),
+        ]);
+        app.run_focus_on("L1");
+        let [left, l1, right] = ["Left", "L1", "Right"].map(|n| app.entity_named(n));
+        let state = |app: &NavEcsMock, e| app.app.world.get::<Focusable>(e).unwrap().state();
+
+        let events = app.run_request(NavRequest::PeekFocus(right));
+        assert_expected_focus_change!(app, &events[..], ["L1"], ["Right"]);
+        assert_eq!(app.currently_focused(), "Right");
+        assert_eq!(state(&app, l1), Prioritized);
+        assert_eq!(state(&app, left), Active);
+
+        // Peeking the same element again does nothing.
+        let events = app.run_request(NavRequest::PeekFocus(right));
+        assert!(events.is_empty(), "{events:#?}");
+
+        // The next request restores the previous focus, then is handled.
+        let events = app.run_request(NavRequest::ScopeMove(events::ScopeDirection::Next));
+        assert_expected_focus_change!(app, &events[..1], ["Right"], ["L1"]);
+        assert_eq!(state(&app, right), Inert);
+        assert_eq!(state(&app, l1), Focused);
+        assert_eq!(state(&app, left), Active);
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[test]
+    fn custom_schedule() {
+        use bevy::ecs::schedule::ScheduleLabel;
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct MenuSchedule;
+
+        let mut app = App::new();
+        let plugin = GenericNavigationPlugin::<MockNavigationStrategy>::new();
+        app.add_plugins(plugin.in_schedule(MenuSchedule));
+        let hierarchy = spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ];
+        hierarchy.spawn(&mut app.world);
+        let mut app = NavEcsMock { app };
+
+        app.app.update();
+        let mut focused = app.app.world.query_filtered::<(), With<Focused>>();
+        assert_eq!(focused.iter(&app.app.world).count(), 0);
+
+        app.app.world.run_schedule(MenuSchedule);
+        assert_eq!(app.currently_focused(), "Initial");
+        let left = app.entity_named("Left");
+        app.app.world.send_event(NavRequest::FocusOn(left));
+        app.app.world.send_event(NavRequest::Action);
+        app.app.world.run_schedule(MenuSchedule);
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[test]
+    fn auto_focusable_custom_schedule() {
+        use crate::systems::AutoFocusableExt;
+        use bevy::ecs::schedule::ScheduleLabel;
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct MenuSchedule;
+        #[derive(Component)]
+        struct MyButton;
+
+        let mut app = App::new();
+        let plugin = GenericNavigationPlugin::<MockNavigationStrategy>::new();
+        app.add_plugins(plugin.in_schedule(MenuSchedule))
+            .auto_focusable::<MyButton>();
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        let button = app.world.spawn((Name::new("Play"), MyButton)).id();
+        app.world.entity_mut(menu).add_child(button);
+        let mut app = NavEcsMock { app };
+
+        app.app.world.run_schedule(MenuSchedule);
+        assert_eq!(app.currently_focused(), "Play");
+    }
+
+    #[cfg(feature = "pointer_focus")]
+    #[test]
+    fn click_activates() {
+        use crate::systems::{click_requests, ClickActivates};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable("Decoration"),
+            focusable("Blocked"),
+        ]);
+        let click = |app: &mut NavEcsMock, name| {
+            let entity = app.entity_named(name);
+            let world = &mut app.app.world;
+            let focusable = world.get::<Focusable>(entity);
+            let requests = click_requests(entity, focusable, world.get::<ClickActivates>(entity));
+            world.send_event_batch(requests);
+            app.app.update();
+            receive_events(&mut app.app.world)
+        };
+        let [initial, decoration, blocked] =
+            ["Initial", "Decoration", "Blocked"].map(|n| app.entity_named(n));
+        let world = &mut app.app.world;
+        world.entity_mut(decoration).insert(ClickActivates(false));
+        world.get_mut::<Focusable>(blocked).unwrap().block();
+
+        let events = click(&mut app, "Decoration");
+        assert!(
+            matches!(events[..], [NavEvent::FocusChanged { .. }]),
+            "{events:#?}"
+        );
+        assert_eq!(app.currently_focused(), "Decoration");
+
+        let events = click(&mut app, "Initial");
+        assert!(
+            events.iter().any(|e| e.is_activated(initial)),
+            "{events:#?}"
+        );
+
+        assert!(click(&mut app, "Blocked").is_empty());
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
+    #[test]
+    fn keyboard_only_action() {
+        use events::InputSource::{Keyboard, Pointer};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("DeleteSave"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ]);
+        let delete_save = app.entity_named("DeleteSave");
+        let sources = ActionSources::KEYBOARD;
+        app.app.world.entity_mut(delete_save).insert(sources);
+
+        let events = app.run_request(NavRequest::ActionFrom(Pointer));
+        assert!(
+            matches!(
+                events[..],
+                [NavEvent::NoChanges {
+                    request: NavRequest::ActionFrom(Pointer),
+                    ..
+                }]
+            ),
+            "{events:#?}"
+        );
+        let events = app.run_request(NavRequest::ActionFrom(Keyboard));
+        assert!(events[0].is_activated(delete_save), "{events:#?}");
+
+        // Other focusables accept all sources.
+        app.run_focus_on("Left");
+        app.run_request(NavRequest::ActionFrom(Pointer));
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[test]
+    fn refocus() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ]);
+        app.run_focus_on("L1");
+        let mut states = app.app.world.query::<(Entity, &Focusable)>();
+        let states_before: Vec<_> = states
+            .iter(&app.app.world)
+            .map(|(e, f)| (e, f.state()))
+            .collect();
+
+        let events = app.run_request(NavRequest::Refocus);
+        assert_expected_focus_change!(app, &events[..], ["L1", "Left"], ["L1", "Left"]);
+        let states_after: Vec<_> = states
+            .iter(&app.app.world)
+            .map(|(e, f)| (e, f.state()))
+            .collect();
+        assert_eq!(states_before, states_after);
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[test]
+    fn focus_id_of_events() {
+        use bevy::ecs::system::SystemState;
+
+        let mut app =
+            NavEcsMock::new(spawn_hierarchy![prioritized("Initial"), focusable("Other"),]);
+        let other = app.entity_named("Other");
+        app.app.world.entity_mut(other).insert(FocusId(7));
+
+        let mut ids = SystemState::<Query<&FocusId>>::new(&mut app.app.world);
+        let events = app.run_focus_on("Other");
+        let focused_ids: Vec<_> = events
+            .iter()
+            .map(|e| e.focused_id(&ids.get(&app.app.world)))
+            .collect();
+        assert_eq!(focused_ids, [Some(FocusId(7))]);
+
+        let events = app.run_focus_on("Initial");
+        assert_eq!(events[0].focused_id(&ids.get(&app.app.world)), None);
+    }
+
+    #[test]
+    fn deep_cancel_restores_every_level() {
+        use FocusState::{Active, Focused, Inert, Prioritized};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable_to("LMid" [
+                    focusable("M1"),
+                    focusable_to("MDeep" [
+                        focusable("D1"),
+                        focusable("D2"),
+                    ]),
+                ]),
+            ]),
+        ]);
+        let states =
+            |app: &mut NavEcsMock| ["Left", "LMid", "MDeep", "D2"].map(|name| app.state_of(name));
+        app.run_focus_on("D2");
+        assert_eq!(states(&mut app), [Active, Active, Active, Focused]);
+
+        // Cancel one level at a time, the levels left stay prioritized.
+        app.run_request(NavRequest::Cancel);
+        app.run_request(NavRequest::Cancel);
+        app.run_request(NavRequest::Cancel);
+        assert_eq!(
+            states(&mut app),
+            [Focused, Prioritized, Prioritized, Prioritized]
+        );
+        for expected in ["LMid", "MDeep", "D2"] {
+            app.run_request(NavRequest::Action);
+            assert_eq!(app.currently_focused(), expected);
+        }
+
+        // Leave the whole branch at once, then re-enter it.
+        app.run_focus_on("Initial");
+        assert_eq!(
+            states(&mut app),
+            [Inert, Prioritized, Prioritized, Prioritized]
+        );
+        app.run_focus_on("Left");
+        for expected in ["LMid", "MDeep", "D2"] {
+            app.run_request(NavRequest::Action);
+            assert_eq!(app.currently_focused(), expected);
+        }
+        assert_eq!(states(&mut app), [Active, Active, Active, Focused]);
+    }
+
+    #[test]
+    fn menu_spawned_in_menuless_app() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("A"),
+            focusable("B"),
+        ]));
+        // Without any `MenuBuilder`, frames go by without navigation changes.
+        for _ in 0..3 {
+            app.app.update();
+            assert!(receive_events::<NavEvent>(&app.app.world).is_empty());
+            assert_eq!(app.currently_focused(), "A");
+        }
+        let mut menus = app.app.world.query::<&resolve::TreeMenu>();
+        assert_eq!(menus.iter(&app.app.world).count(), 0);
+
+        let b = app.entity_named("B");
+        let menu = (MenuSetting::new(), MenuBuilder::EntityParent(b));
+        app.app.world.spawn(menu).with_children(|menu| {
+            menu.spawn((Focusable::new(), Name::new("B1")));
+        });
+        app.app.update();
+
+        app.run_focus_on("B");
+        app.run_request(NavRequest::Action);
+        assert_eq!(app.currently_focused(), "B1");
+    }
+
+    #[test]
+    fn custom_cancel_target() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+                focusable_to("LBottom" [
+                    focusable("LBottomForward"),
+                ]),
+            ]),
+        ]);
+        let initial = app.entity_named("Initial");
+        let nested = app.entity_named("LBottom Menu");
+        let setting = app.app.world.get_mut::<MenuSetting>(nested);
+        setting.unwrap().cancel_to = Some(initial);
+        app.run_focus_on("LBottomForward");
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(
+            app,
+            &events[..],
+            ["LBottomForward", "LBottom", "Left"],
+            ["Initial"],
+        );
+
+        // A target within the menu being left falls back to `focus_parent`.
+        let forward = app.entity_named("LBottomForward");
+        let setting = app.app.world.get_mut::<MenuSetting>(nested);
+        setting.unwrap().cancel_to = Some(forward);
+        app.run_focus_on("LBottomForward");
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LBottomForward", "LBottom"], ["LBottom"]);
+    }
+
+    #[test]
+    fn menu_member_outside_hierarchy() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable("Moved"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ]);
+        let menu = app.entity_named("Left Menu");
+        let moved = app.entity_named("Moved");
+        app.app.world.entity_mut(moved).insert(MenuMember(menu));
+        let tooltip = (Name::new("Tooltip"), Focusable::new(), MenuMember(menu));
+        app.app.world.spawn(tooltip);
+        app.app.update();
+
+        let events = app.run_focus_on("Tooltip");
+        assert_expected_focus_change!(app, &events[..], ["Initial"], ["Tooltip", "Left"]);
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["Tooltip", "Left"], ["Left"]);
+
+        let events = app.run_focus_on("Moved");
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["Moved", "Left"]);
+        assert_eq!(app.state_of("Tooltip"), FocusState::Inert);
+
+        // The last focused member is the menu's active child.
+        app.run_focus_on("Initial");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["Moved", "Left"]);
+    }
+
+    #[test]
+    fn toggle_checkbox() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Checkbox"),
+            focusable_to("Other" [
+                focusable("O1"),
+            ]),
+        ]);
+        let checkbox = app.entity_named("Checkbox");
+        app.app.world.entity_mut(checkbox).insert(Toggleable(false));
+        for expected in [true, false, true] {
+            let events = app.run_request(NavRequest::Action);
+            assert!(
+                matches!(
+                    events[..],
+                    [NavEvent::Toggled { entity, value }] if entity == checkbox && value == expected
+                ),
+                "{events:#?}"
+            );
+            let toggleable = app.app.world.get::<Toggleable>(checkbox).unwrap();
+            assert_eq!(toggleable.0, expected);
+        }
+        assert_eq!(app.currently_focused(), "Checkbox");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn wrap_single_row() {
+        use crate::resolve::UiProjectionQuery;
+        use bevy::ecs::system::SystemState;
+        use events::Direction::{East, West};
+
+        let mut world = World::new();
+        let row: Vec<_> = (0..5)
+            .map(|i| {
+                let transform = Transform::from_xyz(i as f32 * 100.0, 50.0, 0.0);
+                world.spawn(GlobalTransform::from(transform)).id()
+            })
+            .collect();
+        let mut state = SystemState::<UiProjectionQuery>::new(&mut world);
+        let strategy = state.get(&world);
+        assert_eq!(strategy.resolve_2d(row[4], East, true, &row), Some(&row[0]));
+        assert_eq!(strategy.resolve_2d(row[0], West, true, &row), Some(&row[4]));
+        assert_eq!(strategy.resolve_2d(row[4], East, false, &row), None);
+        assert_eq!(strategy.resolve_2d(row[1], East, true, &row), Some(&row[2]));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn focus_nearest_to_position() {
+        use crate::resolve::FocusNearest;
+        use bevy::ecs::system::SystemState;
+
+        let mut world = World::new();
+        world.init_resource::<Events<NavRequest>>();
+        let mut state = SystemState::<FocusNearest>::new(&mut world);
+        // No focusables, nothing to focus.
+        assert_eq!(state.get_mut(&mut world).focus_nearest_to(Vec2::ZERO), None);
+
+        let mut spawn = |x, focusable| {
+            let transform = GlobalTransform::from(Transform::from_xyz(x, 50.0, 0.0));
+            world.spawn((transform, focusable)).id()
+        };
+        let near = spawn(100.0, Focusable::new());
+        let _blocked = spawn(190.0, Focusable::new().blocked());
+        let _far = spawn(320.0, Focusable::new());
+        let nearest = state
+            .get_mut(&mut world)
+            .focus_nearest_to(Vec2::new(200.0, 50.0));
+        state.apply(&mut world);
+        assert_eq!(nearest, Some(near));
+        let requests: Vec<NavRequest> = receive_events(&world);
+        assert!(
+            matches!(requests[..], [NavRequest::FocusOn(e)] if e == near),
+            "{requests:#?}"
+        );
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn focus_neighbors() {
+        use crate::resolve::{FocusNeighbors, UiProjectionQuery};
+        use bevy::ecs::system::SystemState;
+        use events::Direction::*;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        let grid = [(100.0, 100.0), (100.0, 0.0), (200.0, 100.0), (200.0, 200.0)];
+        let mut spawn = |x, y| {
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            let entity = app.world.spawn((Focusable::new(), transform)).id();
+            app.world.entity_mut(menu).add_child(entity);
+            entity
+        };
+        let [center, top, right, bottom_right] = grid.map(|(x, y)| spawn(x, y));
+        // Not in the menu, never a neighbor.
+        let outside = (Focusable::new(), GlobalTransform::from_xyz(0.0, 100.0, 0.0));
+        app.world.spawn(outside);
+        app.update();
+
+        let mut state = SystemState::<FocusNeighbors>::new(&mut app.world);
+        let neighbors = state.get(&app.world).neighbors(center);
+        let expected = [(North, top), (East, right), (SouthEast, bottom_right)];
+        assert_eq!(neighbors, expected.into_iter().collect());
+
+        // Right-to-left, the requested directions are mirrored.
+        app.insert_resource(NavDirectionality::Rtl);
+        let neighbors = state.get(&app.world).neighbors(center);
+        let expected = [(North, top), (West, right), (SouthWest, bottom_right)];
+        assert_eq!(neighbors, expected.into_iter().collect());
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn ui_grid_query() {
+        use crate::resolve::UiGridQuery;
+        use bevy::ui::{GridPlacement, Style};
+        use events::Direction::*;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiGridQuery>::new());
+        let menu = app
+            .world
+            .spawn((MenuSetting::new().wrapping(), MenuBuilder::Root))
+            .id();
+        // "Tall" spans two rows, its center is aligned with "Low"
+        // rather than "High", which is in the same row.
+        let cells = [
+            ("Tall", 1, 1, (50.0, 100.0)),
+            ("High", 1, 2, (150.0, 50.0)),
+            ("Low", 2, 2, (150.0, 100.0)),
+            ("Far", 1, 3, (250.0, 50.0)),
+        ];
+        for (name, row, column, (x, y)) in cells {
+            let style = Style {
+                grid_row: GridPlacement::start(row),
+                grid_column: GridPlacement::start(column),
+                ..default()
+            };
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            let focusable = (Focusable::new(), Name::new(name), style, transform);
+            let entity = app.world.spawn(focusable).id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+
+        app.run_focus_on("Tall");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "High");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "Low");
+
+        // Wraps to the other end of the row.
+        app.run_focus_on("Tall");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "Far");
+    }
+
+    // `FocusOn` is processed before other requests sent in the same frame.
+    #[test]
+    fn request_processing_order() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+            ]),
+        ]);
+        let left = app.entity_named("Left");
+        app.app.world.send_event(NavRequest::Action);
+        let events = app.run_request(NavRequest::FocusOn(left));
+        assert_eq!(events.len(), 2, "{events:#?}");
+        assert_expected_focus_change!(app, &events[..1], ["Initial"], ["Left"]);
+        assert_expected_focus_change!(app, &events[1..], ["Left"], ["L1", "Left"]);
+        assert_eq!(app.currently_focused(), "L1");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn skip_clipped_focusables() {
+        use crate::resolve::{SkipClipped, UiProjectionQuery};
+        use bevy::{ecs::system::SystemState, math::Rect, ui::CalculatedClip};
+        use events::Direction::South;
+
+        let mut world = World::new();
+        let clip = CalculatedClip {
+            clip: Rect::new(0.0, 0.0, 100.0, 250.0),
+        };
+        let column: Vec<_> = (0..4)
+            .map(|i| {
+                let transform = Transform::from_xyz(50.0, i as f32 * 100.0, 0.0);
+                let node = (Node::default(), clip);
+                world.spawn((GlobalTransform::from(transform), node)).id()
+            })
+            .collect();
+        // Move the item at y=100 out of the clip rect, like scrolling would.
+        let scrolled = Transform::from_xyz(500.0, 100.0, 0.0);
+        world
+            .entity_mut(column[1])
+            .insert(GlobalTransform::from(scrolled));
+
+        let mut state = SystemState::<UiProjectionQuery>::new(&mut world);
+        let strategy = state.get(&world);
+        assert!(strategy.is_visible(column[1]));
+
+        world.init_resource::<SkipClipped>();
+        let mut state = SystemState::<UiProjectionQuery>::new(&mut world);
+        let strategy = state.get(&world);
+        assert!(!strategy.is_visible(column[1]));
+        assert!(!strategy.is_visible(column[3]));
+        assert_eq!(
+            strategy.resolve_2d(column[0], South, false, &column),
+            Some(&column[2])
+        );
+        assert_eq!(strategy.resolve_2d(column[2], South, false, &column), None);
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn skip_offscreen_focusables() {
+        use crate::resolve::{SkipOffscreen, UiProjectionQuery};
+        use events::Direction::East;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new())
+            .insert_resource(SkipOffscreen {
+                viewport: Some(Rect::new(0.0, 0.0, 200.0, 100.0)),
+            });
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        for (name, x) in [("A", 50.0), ("B", 150.0), ("C", 250.0)] {
+            let focusable = if name == "A" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, 50.0, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "A");
+
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "B");
+        // "C" is outside of the viewport.
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "B");
+
+        // Scroll "B" out of the viewport, focus goes to the nearest visible focusable.
+        let b = app.entity_named("B");
+        let scrolled = GlobalTransform::from_xyz(-100.0, 50.0, 0.0);
+        app.app.world.entity_mut(b).insert(scrolled);
+        app.app.update();
+        app.app.update();
+        assert_eq!(app.currently_focused(), "A");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn focus_magnet_wins_over_closer_sibling() {
+        use crate::resolve::UiProjectionQuery;
+        use bevy::time::Time;
+        use events::Direction::{East, West};
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new())
+            .init_resource::<Time>();
+        let focusables = [
+            ("Start", Focusable::new().prioritized(), Vec2::ZERO),
+            ("Near", Focusable::new(), Vec2::new(100.0, 0.0)),
+            ("Far", Focusable::new(), Vec2::new(130.0, 40.0)),
+        ];
+        for (name, focusable, pos) in focusables {
+            let transform = GlobalTransform::from_xyz(pos.x, pos.y, 0.0);
+            app.world.spawn((Name::new(name), focusable, transform));
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+
+        let far = app.entity_named("Far");
+        let magnet = FocusMagnet::new(1.0).with_ttl(0.5);
+        app.app.world.entity_mut(far).insert(magnet);
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Far");
+
+        // The magnet is removed after its ttl.
+        app.app
+            .world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.app.update();
+        assert!(app.app.world.get::<FocusMagnet>(far).is_none());
+        app.run_request(NavRequest::Move(West));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Near");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn candidates_2d() {
+        use crate::resolve::UiProjectionQuery;
+        use bevy::ecs::system::SystemState;
+        use events::Direction::East;
+
+        let mut world = World::new();
+        let mut spawn = |x: f32, y: f32| {
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            world.spawn((Focusable::new(), transform)).id()
+        };
+        let (a, b, c, d) = (
+            spawn(0., 0.),
+            spawn(100., 0.),
+            spawn(200., 0.),
+            spawn(150., 60.),
+        );
+        let west = spawn(-100., 0.);
+        // An attraction of 4 brings the score of `c` from 200 to 50.
+        world.entity_mut(c).insert(FocusMagnet::new(3.0));
+
+        let mut state = SystemState::<UiProjectionQuery>::new(&mut world);
+        let query = state.get(&world);
+        let candidates = query.candidates_2d(a, East, &[a, b, c, d, west]);
+        let entities: Vec<_> = candidates.iter().map(|(e, _)| *e).collect();
+        assert_eq!(entities, [c, b, d]);
+        assert_eq!(candidates[0].1, 50.0);
+        assert_eq!(candidates[1].1, 100.0);
+        assert!(candidates[2].1 > 100.0);
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn stacked_focusables_prefer_topmost() {
+        use crate::resolve::UiProjectionQuery;
+        use bevy::ecs::system::SystemState;
+        use events::Direction::East;
+
+        let mut world = World::new();
+        let mut spawn = |x: f32, z: f32| {
+            let transform = GlobalTransform::from_xyz(x, 0.0, z);
+            world.spawn((Focusable::new(), transform)).id()
+        };
+        let start = spawn(0.0, 0.0);
+        let top = spawn(100.0, 2.0);
+        let bottom = spawn(100.0, 1.0);
+
+        let mut state = SystemState::<UiProjectionQuery>::new(&mut world);
+        let query = state.get(&world);
+        let point = Vec2::new(100.0, 0.0);
+        assert_eq!(query.nearest_to(point, &[bottom, top]), Some(&top));
+        assert_eq!(query.nearest_to(point, &[top, bottom]), Some(&top));
+        let siblings = [start, top, bottom];
+        assert_eq!(query.resolve_2d(start, East, false, &siblings), Some(&top));
+        let siblings = [start, bottom, top];
+        assert_eq!(query.resolve_2d(start, East, false, &siblings), Some(&top));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn default_focus_style() {
+        use crate::components::{apply_focus_style, DefaultFocusStyle};
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        let style = (DefaultFocusStyle, BackgroundColor::default());
+        let initial = app.entity_named("Initial");
+        let left = app.entity_named("Left");
+        app.app.world.entity_mut(initial).insert(style);
+        app.app.world.entity_mut(left).insert(style);
+        app.app.add_systems(
+            Update,
+            apply_focus_style::<DefaultFocusStyle>.after(NavRequestSystem),
+        );
+        let background =
+            |app: &NavEcsMock, entity| app.app.world.get::<BackgroundColor>(entity).unwrap().0;
+
+        app.app.update();
+        assert_eq!(background(&app, initial), Color::ORANGE_RED);
+        assert_eq!(background(&app, left), Color::DARK_GRAY);
+
+        app.run_focus_on("Left");
+        assert_eq!(background(&app, initial), Color::DARK_GRAY);
+        assert_eq!(background(&app, left), Color::ORANGE_RED);
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn focus_ring_follows_focus() {
+        use crate::components::{draw_focus_ring, FocusRing, FocusRingNode};
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        for (name, x) in [("Initial", 10.), ("Left", 50.)] {
+            let entity = app.entity_named(name);
+            let transform = GlobalTransform::from_xyz(x, 20., 0.);
+            app.app
+                .world
+                .entity_mut(entity)
+                .insert((Node::default(), transform));
+        }
+        let ring = FocusRing {
+            speed: f32::INFINITY,
+            ..default()
+        };
+        app.app
+            .insert_resource(ring)
+            .init_resource::<Time>()
+            .add_systems(Update, draw_focus_ring.after(NavRequestSystem));
+        let mut ring_style = app
+            .app
+            .world
+            .query_filtered::<&Style, With<FocusRingNode>>();
+
+        // The first run spawns the ring node.
+        app.app.update();
+        app.run_focus_on("Left");
+        let style = ring_style.single(&app.app.world);
+        assert_eq!(style.display, Display::Flex);
+        assert_eq!((style.left, style.top), (Val::Px(47.), Val::Px(17.)));
+        assert_eq!(style.width, Val::Px(6.));
+
+        app.run_focus_on("Initial");
+        let style = ring_style.single(&app.app.world);
+        assert_eq!((style.left, style.top), (Val::Px(7.), Val::Px(17.)));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn focus_anim_eases_in_and_out() {
+        use crate::components::{animate_focus, FocusAnim};
+        use std::time::Duration;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        let initial = app.entity_named("Initial");
+        let left = app.entity_named("Left");
+        app.app
+            .world
+            .entity_mut(initial)
+            .insert(FocusAnim::default());
+        app.app.world.entity_mut(left).insert(FocusAnim::default());
+        app.app
+            .init_resource::<Time>()
+            .add_systems(Update, animate_focus.after(NavRequestSystem));
+        let time = Duration::from_millis(50);
+        app.app.world.resource_mut::<Time>().advance_by(time);
+        let intensity = |app: &NavEcsMock, entity| {
+            let anim = app.app.world.get::<FocusAnim>(entity).unwrap();
+            anim.intensity
+        };
+
+        app.app.update();
+        let first = intensity(&app, initial);
+        assert!(0.0 < first && first < 1.0, "{first}");
+        app.app.update();
+        assert!(intensity(&app, initial) > first);
+        assert_eq!(intensity(&app, left), 0.0);
+
+        app.run_focus_on("Left");
+        assert!(intensity(&app, left) > 0.0);
+        assert!(intensity(&app, initial) < first);
+        for _ in 0..30 {
+            app.app.update();
+        }
+        assert_eq!(intensity(&app, left), 1.0);
+        assert_eq!(intensity(&app, initial), 0.0);
+    }
+
+    #[test]
+    fn initial_focus_id() {
+        let hierarchy = || {
+            spawn_hierarchy![
+                prioritized("Initial"),
+                focusable_to("Left" [
+                    focusable("L1"),
+                    focusable("L2"),
+                ]),
+            ]
+        };
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new())
+            .insert_resource(InitialFocus(FocusId(2)));
+        hierarchy().spawn(&mut app.world);
+        let mut app = NavEcsMock { app };
+        let l2 = app.entity_named("L2");
+        app.app.world.entity_mut(l2).insert(FocusId(2));
+        app.app.update();
+        assert_eq!(app.currently_focused(), "L2");
+        assert_eq!(app.state_of("Left"), FocusState::Active);
+
+        // Unknown ids fall back to the prioritized focusable.
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new())
+            .insert_resource(InitialFocus(FocusId(3)));
+        hierarchy().spawn(&mut app.world);
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
+    #[test]
+    fn emit_no_changes() {
+        use events::Direction::West;
+
+        let hierarchy = || {
+            spawn_hierarchy!(@rootless [
+                prioritized("Initial"),
+                focusable("Right"),
+            ])
+        };
+        let mut app = NavEcsMock::new(hierarchy());
+        let events = app.run_request(NavRequest::Move(West));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+
+        let mut app = App::new();
+        let plugin = GenericNavigationPlugin::<MockNavigationStrategy>::new();
+        app.add_plugins(plugin.emit_no_changes(false));
+        hierarchy().spawn(&mut app.world);
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert!(app.run_request(NavRequest::Move(West)).is_empty());
+        assert!(app.run_request(NavRequest::Cancel).is_empty());
+        // Activations are still sent.
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(
+            events[..],
+            [NavEvent::NoChanges {
+                request: NavRequest::Action,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn no_focusable_grace() {
+        use bevy::utils::tracing::{
+            span, subscriber::with_default, Event, Level, Metadata, Subscriber,
+        };
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        /// Count the warnings logged.
+        struct WarnCount(Arc<AtomicUsize>);
+        impl Subscriber for WarnCount {
+            fn enabled(&self, _: &Metadata) -> bool {
+                true
+            }
+            fn new_span(&self, _: &span::Attributes) -> span::Id {
+                span::Id::from_u64(1)
+            }
+            fn record(&self, _: &span::Id, _: &span::Record) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, event: &Event) {
+                if *event.metadata().level() == Level::WARN {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let count = || warnings.load(Ordering::SeqCst);
+
+        with_default(WarnCount(warnings.clone()), || {
+            let mut app = App::new();
+            let plugin = GenericNavigationPlugin::<MockNavigationStrategy>::new();
+            app.add_plugins(plugin.no_focusable_grace(3));
+            for _ in 0..3 {
+                app.world.send_event(NavRequest::Action);
+                app.update();
+            }
+            assert_eq!(count(), 0);
+
+            // The UI spawns in time, no warnings.
+            spawn_hierarchy!(@rootless [prioritized("Initial")]).spawn(&mut app.world);
+            app.update();
+            let mut app = NavEcsMock { app };
+            app.run_request(NavRequest::Action);
+            assert_eq!(app.currently_focused(), "Initial");
+            assert_eq!(count(), 0);
+
+            // The UI never spawns.
+            let mut app = App::new();
+            let plugin = GenericNavigationPlugin::<MockNavigationStrategy>::new();
+            app.add_plugins(plugin.no_focusable_grace(3));
+            for _ in 0..5 {
+                app.world.send_event(NavRequest::Action);
+                app.update();
+            }
+            assert_eq!(count(), 2);
+        });
+    }
+
+    #[test]
+    fn filter_skips_focusables() {
+        use crate::resolve::ChildQueries;
+        use bevy::ecs::system::SystemState;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable("One"),
+            focusable("Two"),
+            focusable("Three"),
+            focusable("Four"),
+        ]);
+        let root = app.entity_named("Root");
+        let world = &mut app.app.world;
+        world.insert_resource(FocusableFilter::new(|e: Entity| e.index() & 1 == 0));
+
+        let mut state = SystemState::<ChildQueries>::new(world);
+        let focusables = state.get(world).focusables_of(root);
+        assert!(!focusables.is_empty());
+        assert!(focusables.iter().all(|e| e.index() & 1 == 0));
+    }
+
+    // The `from` path is fully deactivated and the `to` path fully activated
+    // when the focus moves between menu trees without common ancestor.
+    #[test]
+    fn focus_on_disjoint_trees() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@roots [
+            "Left Root" [
+                focusable_to("LMenu" [
+                    prioritized("LInner"),
+                    focusable("LInner2"),
+                ]),
+                focusable("LOther"),
+            ],
+            "Right Root" [
+                focusable("RFirst"),
+                focusable_to("RMenu" [
+                    focusable("RInner"),
+                ]),
+            ],
+        ]));
+        use FocusState::{Active, Focused, Inert, Prioritized};
+        assert_eq!(app.currently_focused(), "LInner");
+        assert_eq!(app.state_of("LMenu"), Active);
+
+        let events = app.run_focus_on("RInner");
+        assert_expected_focus_change!(app, &events[..], ["LInner", "LMenu"], ["RInner", "RMenu"]);
+        assert_eq!(app.state_of("LInner"), Prioritized);
+        assert_eq!(app.state_of("LMenu"), Inert);
+        assert_eq!(app.state_of("RInner"), Focused);
+        assert_eq!(app.state_of("RMenu"), Active);
+
+        let events = app.run_focus_on("LOther");
+        assert_expected_focus_change!(app, &events[..], ["RInner", "RMenu"], ["LOther"]);
+        assert_eq!(app.state_of("RMenu"), Inert);
+        assert_eq!(app.state_of("LOther"), Focused);
+    }
+
+    #[test]
+    fn auto_activate_single_focusable_menus() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Chain" [
+                focusable_to("Single" [
+                    focusable("Leaf"),
+                ]),
+            ]),
+        ]);
+        let mut query = app.app.world.query::<(&Name, &mut MenuSetting)>();
+        for (name, mut setting) in query.iter_mut(&mut app.app.world) {
+            if name.as_str() != "Root" {
+                setting.auto_activate_single = true;
+            }
+        }
+        app.run_focus_on("Chain");
+        let events = app.run_request(NavRequest::Action);
+        assert_eq!(events.len(), 3, "{events:#?}");
+        assert_expected_focus_change!(app, &events[..1], ["Chain"], ["Single", "Chain"]);
+        assert_expected_focus_change!(app, &events[1..2], ["Single"], ["Leaf", "Single"]);
+        let leaf = app.entity_named("Leaf");
+        assert!(events[2].is_activated(leaf));
+        assert_eq!(app.currently_focused(), "Leaf");
+    }
+
+    // Entering a menu where all focusables are blocked doesn't change focus.
+    #[test]
+    fn action_into_blocked_menu() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Locked" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+        ]);
+        let mut query = app.app.world.query::<(&Name, &mut Focusable)>();
+        for (name, mut focusable) in query.iter_mut(&mut app.app.world) {
+            if name.starts_with('L') && name.as_str() != "Locked" {
+                focusable.block();
+            }
+        }
+        app.run_focus_on("Locked");
+        let events = app.run_request(NavRequest::Action);
+        assert!(
+            matches!(
+                events[..],
+                [NavEvent::NoChanges {
+                    request: NavRequest::Action,
+                    ..
+                }]
+            ),
+            "{events:#?}"
+        );
+        assert_eq!(app.currently_focused(), "Locked");
+        assert_eq!(app.state_of("L1"), FocusState::Blocked);
+    }
+
+    #[test]
+    fn focus_changed_throttled() {
+        use crate::events::FocusThrottle;
+        use bevy::ecs::system::SystemState;
+        use bevy::time::Time;
+        use std::time::Duration;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        app.app.init_resource::<Time>();
+        let mut state = SystemState::<(EventReader<NavEvent>, Res<Time>)>::new(&mut app.app.world);
+        let mut throttle = FocusThrottle::default();
+        let min_interval = Duration::from_millis(100);
+        let mut focus_on = |app: &mut NavEcsMock, name: &str, elapsed: u64| {
+            let time = Duration::from_millis(elapsed);
+            app.app.world.resource_mut::<Time>().advance_by(time);
+            app.run_focus_on(name);
+            let (mut events, time) = state.get_mut(&mut app.app.world);
+            events.focus_changed_throttled(min_interval, &mut throttle, &time)
+        };
+        let left = app.entity_named("Left");
+        let initial = app.entity_named("Initial");
+        assert_eq!(focus_on(&mut app, "Left", 10), Some(left));
+        assert_eq!(focus_on(&mut app, "Initial", 50), None);
+        assert_eq!(focus_on(&mut app, "Left", 20), None);
+        assert_eq!(focus_on(&mut app, "Initial", 40), Some(initial));
+    }
+
+    #[test]
+    fn nav_scope_stack() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@roots [
+            "Game" [
+                focusable("Play"),
+                prioritized("Quit"),
+            ],
+            "Pause" [
+                prioritized("Resume"),
+                focusable_to("Options" [
+                    focusable("Sound"),
+                ]),
+            ],
+        ]));
+        app.app.init_resource::<NavScopeStack>();
+        app.run_focus_on("Play");
+        let pause = app.entity_named("Pause");
+        app.app.world.resource_mut::<NavScopeStack>().push(pause);
+        app.app.update();
+        assert_eq!(app.currently_focused(), "Resume");
+
+        // Navigation within the top scope, including submenus, works.
+        app.run_focus_on("Sound");
+        assert_eq!(app.currently_focused(), "Sound");
+        let events = app.run_focus_on("Quit");
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "Sound");
+
+        let popped = app.app.world.resource_mut::<NavScopeStack>().pop();
+        assert_eq!(popped, Some(pause));
+        app.app.update();
+        assert_eq!(app.currently_focused(), "Play");
+        app.run_focus_on("Quit");
+        assert_eq!(app.currently_focused(), "Quit");
+    }
+
+    #[test]
+    fn action_and_close() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@roots [
+            "Root" [
+                prioritized("Apply Root"),
+                focusable_to("Options" [
+                    focusable("Apply"),
+                    focusable("Other"),
+                ]),
+            ],
+        ]));
+        app.run_focus_on("Other");
+        for name in ["Apply Root", "Apply"] {
+            let entity = app.entity_named(name);
+            let focusable = Focusable::action_and_close();
+            app.app.world.entity_mut(entity).insert(focusable);
+        }
+        app.run_focus_on("Apply");
+        let apply = app.entity_named("Apply");
+        let events = app.run_request(NavRequest::Action);
+        assert_eq!(events.len(), 2, "Expected an activation then a cancel");
+        assert!(matches!(
+            &events[..1],
+            [NavEvent::NoChanges { from, request: NavRequest::Action }] if *from.first() == apply
+        ));
+        assert_expected_focus_change!(app, &events[1..], ["Apply", "Options"], ["Options"]);
+        assert_eq!(app.currently_focused(), "Options");
+
+        // Nothing to close in the root menu.
+        app.run_focus_on("Apply Root");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(
+            events[..],
+            [NavEvent::NoChanges {
+                request: NavRequest::Action,
+                ..
+            }]
+        ));
+        assert_eq!(app.currently_focused(), "Apply Root");
+    }
+
+    #[test]
+    fn action_target() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@roots [
+            "Root" [
+                prioritized("Play"),
+                focusable_to("Settings" [
+                    focusable("Sound"),
+                    focusable("Video"),
+                ]),
+                focusable("Shortcut"),
+                focusable("Broken"),
+            ],
+        ]));
+        let (shortcut, broken) = (app.entity_named("Shortcut"), app.entity_named("Broken"));
+        let target = ActionTarget::new("Settings Menu");
+        app.app.world.entity_mut(shortcut).insert(target);
+        let target = ActionTarget::new("Missing Menu");
+        app.app.world.entity_mut(broken).insert(target);
+
+        app.run_focus_on("Shortcut");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Shortcut"], ["Sound", "Settings"]);
+        assert_eq!(app.currently_focused(), "Sound");
+        assert_eq!(app.state_of("Settings"), FocusState::Active);
+
+        app.run_focus_on("Broken");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "Broken");
+    }
+
+    #[test]
+    fn auto_focusable() {
+        use crate::systems::AutoFocusableExt;
+
+        #[derive(Component)]
+        struct MyButton {
+            play: bool,
+        }
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new())
+            .auto_focusable_with(|button: &MyButton| {
+                if button.play {
+                    Focusable::new().prioritized()
+                } else {
+                    Focusable::new()
+                }
+            });
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        let buttons = [("Options", false), ("Play", true), ("Quit", false)];
+        for (name, play) in buttons {
+            let button = app.world.spawn((Name::new(name), MyButton { play }));
+            let button = button.id();
+            app.world.entity_mut(menu).add_child(button);
+        }
+        let cancel = (
+            Name::new("Back"),
+            MyButton { play: false },
+            Focusable::cancel(),
+        );
+        let back = app.world.spawn(cancel).id();
+        app.world.entity_mut(menu).add_child(back);
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "Play");
+        let back = app.app.world.get::<Focusable>(back).unwrap();
+        assert_eq!(back, &Focusable::cancel());
+
+        app.run_focus_on("Quit");
+        assert_eq!(app.currently_focused(), "Quit");
+    }
+
+    // Blocking the focused or an active focusable moves focus to a sibling first.
+    #[test]
+    fn block_focused() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+                focusable("LBottom"),
+            ]),
+            focusable("Right"),
+        ]);
+        let block = |app: &mut NavEcsMock, name: &str| {
+            let entity = app.entity_named(name);
+            let mut focusable = app.app.world.get_mut::<Focusable>(entity).unwrap();
+            assert!(focusable.block());
+            app.app.update();
+            receive_events(&app.app.world)
+        };
+        app.run_focus_on("LTop");
+        let events = block(&mut app, "LTop");
+        assert_expected_focus_change!(app, &events[..], ["LTop"], ["LBottom"]);
+        assert_eq!(app.state_of("LTop"), FocusState::Blocked);
+
+        let events = block(&mut app, "Left");
+        assert_expected_focus_change!(app, &events[..], ["LBottom", "Left"], ["Right"]);
+        assert_eq!(app.state_of("Left"), FocusState::Blocked);
+        assert_eq!(app.currently_focused(), "Right");
+    }
+
+    #[test]
+    fn nav_request_from_other_thread() {
+        use crate::events::NavRequestSender;
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Left"),
+        ]));
+        let sender = app.app.world.resource::<NavRequestSender>().clone();
+        let left = app.entity_named("Left");
+        std::thread::spawn(move || sender.send(NavRequest::FocusOn(left)))
+            .join()
+            .unwrap()
+            .unwrap();
+        app.app.update();
+        assert_eq!(app.currently_focused(), "Left");
+    }
+
+    // A menu spawned with both a `TreeMenu` and a `MenuBuilder`, like a scene
+    // saved with the navigation state, keeps its `TreeMenu`.
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn pre_existing_tree_menu() {
+        use crate::resolve::TreeMenu;
+        use bevy::{scene::DynamicSceneBuilder, utils::HashMap};
+
+        let register_hierarchy = |app: &mut App| {
+            app.register_type::<Name>()
+                .register_type::<Parent>()
+                .register_type::<Children>();
+        };
+        let mut saved = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+        ]);
+        register_hierarchy(&mut saved.app);
+        let [left, l2, menu] = ["Left", "L2", "Left Menu"].map(|n| saved.entity_named(n));
+        saved.app.world.entity_mut(menu).insert((
+            MenuBuilder::from_named("Left"),
+            TreeMenu::new(Some(left), l2),
+        ));
+        let world = &saved.app.world;
+        let entities = world.iter_entities().map(|e| e.id());
+        let scene = DynamicSceneBuilder::from_world(world)
+            .extract_entities(entities)
+            .build();
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<MockNavigationStrategy>::new());
+        register_hierarchy(&mut app);
+        let mut entity_map = HashMap::default();
+        scene
+            .write_to_world(&mut app.world, &mut entity_map)
+            .unwrap();
+        app.update();
+        let mut app = NavEcsMock { app };
+        let menu = entity_map[&menu];
+        assert!(app.app.world.get::<MenuBuilder>(menu).is_none());
+        let mut menus = app.app.world.query::<&TreeMenu>();
+        assert_eq!(menus.iter(&app.app.world).count(), 2);
+
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["L2", "Left"]);
+    }
+
+    #[test]
+    fn scope_move_2d_tab_grid() {
+        use events::Direction::{East, North, South, West};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("TopLeft"),
+            focusable("TopRight"),
+            focusable_to("BottomLeft" [
+                focusable("Content"),
+            ]),
+            focusable("BottomRight"),
+        ]);
+        let grid = [
+            ("TopLeft", 0., 0.),
+            ("TopRight", 10., 0.),
+            ("BottomLeft", 0., 10.),
+            ("BottomRight", 10., 10.),
+        ];
+        for (name, x, y) in grid {
+            let entity = app.entity_named(name);
+            let transform = Transform::from_xyz(x, y, 0.);
+            app.app.world.entity_mut(entity).insert(transform);
+        }
+        let root = app.entity_named("Root");
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().scope = true;
+
+        app.run_request(NavRequest::ScopeMove2d(East));
+        assert_eq!(app.currently_focused(), "TopRight");
+        app.run_request(NavRequest::ScopeMove2d(South));
+        assert_eq!(app.currently_focused(), "BottomRight");
+        let events = app.run_request(NavRequest::ScopeMove2d(West));
+        assert_expected_focus_change!(app, &events[..], ["BottomRight"], ["Content", "BottomLeft"]);
+        // From within a submenu, move in the encompassing scope menu.
+        app.run_request(NavRequest::ScopeMove2d(North));
+        assert_eq!(app.currently_focused(), "TopLeft");
+        assert_eq!(app.state_of("BottomLeft"), FocusState::Inert);
+    }
+
+    #[test]
+    fn move_overflow_scopes() {
+        use bevy::math::IVec2;
+        use events::{
+            Direction::{North, South},
+            ScopeDirection,
+        };
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable_to("Tab1" [
+                prioritized("T1Top"),
+                focusable("T1Bottom"),
+            ]),
+            focusable_to("Tab2" [
+                focusable("T2Content"),
+            ]),
+        ]);
+        let root = app.entity_named("Root");
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().scope = true;
+        for (name, y) in [("T1Top", 0.0), ("T1Bottom", 100.0)] {
+            let entity = app.entity_named(name);
+            let transform = Transform::from_xyz(0.0, y, 0.0);
+            app.app.world.entity_mut(entity).insert(transform);
+        }
+        let tab1_menu = app.entity_named("Tab1 Menu");
+        let mut setting = app.app.world.get_mut::<MenuSetting>(tab1_menu).unwrap();
+        *setting = setting.move_overflow_scopes();
+
+        app.run_focus_on("T1Top");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "T1Bottom");
+
+        let events = app.run_request(NavRequest::Move(South));
+        assert_expected_focus_change!(
+            app,
+            &events[..],
+            ["T1Bottom", "Tab1"],
+            ["T2Content", "Tab2"]
+        );
+
+        // Steps of a `MoveBy` overflow the same way.
+        app.run_focus_on("T1Top");
+        let events = app.run_request(NavRequest::MoveBy(IVec2::new(0, 2)));
+        assert_expected_focus_change!(app, &events[..], ["T1Top", "Tab1"], ["T2Content", "Tab2"]);
+
+        // Without a previous tab, this is the edge of the scope menu.
+        app.run_focus_on("T1Top");
+        let events = app.run_request(NavRequest::Move(North));
+        assert!(matches!(
+            events[..],
+            [NavEvent::ScopeEdge { menu, direction: ScopeDirection::Previous }] if menu == root
+        ));
+        assert_eq!(app.currently_focused(), "T1Top");
+    }
+
+    #[test]
+    fn scope_stays_on_tabs() {
+        use events::ScopeDirection::{Next, Previous};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Tab1"),
+            focusable_to("Tab2" [
+                focusable("T2Content1"),
+                prioritized("T2Content2"),
+            ]),
+            focusable_to("Tab3" [
+                focusable("T3Content"),
+            ]),
+        ]);
+        let root = app.entity_named("Root");
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().scope = true;
+
+        // By default, the tab bar enters the content.
+        let events = app.run_request(NavRequest::ScopeMove(Next));
+        assert_expected_focus_change!(app, &events[..], ["Tab1"], ["T2Content2", "Tab2"]);
+        app.run_request(NavRequest::ScopeMove(Previous));
+        assert_eq!(app.currently_focused(), "Tab1");
+
+        // With `scope_stays_on_tabs`, focus stays on the tab bar.
+        let mut setting = app.app.world.get_mut::<MenuSetting>(root).unwrap();
+        *setting = setting.scope_stays_on_tabs();
+        let events = app.run_request(NavRequest::ScopeMove(Next));
+        assert_expected_focus_change!(app, &events[..], ["Tab1"], ["Tab2"]);
+
+        // From within a tab's content, focus goes into the next tab's content.
+        app.run_focus_on("T2Content1");
+        let events = app.run_request(NavRequest::ScopeMove(Next));
+        assert_expected_focus_change!(
+            app,
+            &events[..],
+            ["T2Content1", "Tab2"],
+            ["T3Content", "Tab3"]
+        );
+    }
+
+    #[test]
+    fn focus_changed_within_menu() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable("Other"),
+            focusable_to("Sub" [
+                focusable("S1"),
+                focusable("S2"),
+            ]),
+        ]);
+        let within = |events: Vec<NavEvent>| match events[..] {
+            [NavEvent::FocusChanged { within_menu, .. }] => within_menu,
+            _ => panic!("Expected a single FocusChanged, got {events:?}"),
+        };
+        assert!(within(app.run_focus_on("Other")));
+        assert!(within(app.run_focus_on("Sub")));
+        // Entering and leaving a menu.
+        assert!(!within(app.run_request(NavRequest::Action)));
+        assert_eq!(app.currently_focused(), "S1");
+        assert!(within(app.run_focus_on("S2")));
+        assert!(!within(app.run_request(NavRequest::Cancel)));
+        assert!(!within(app.run_focus_on("S1")));
+        assert!(!within(app.run_focus_on("Initial")));
+    }
+
+    #[test]
+    fn scope_skips_blocked() {
+        use events::ScopeDirection::{Next, Previous};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("T1"),
+            focusable("T2"),
+            focusable("T3"),
+            focusable("T4"),
+            focusable("T5"),
+        ]);
+        let root = app.entity_named("Root");
+        let mut setting = app.app.world.get_mut::<MenuSetting>(root).unwrap();
+        setting.scope = true;
+        setting.wrapping = true;
+        let t3 = app.entity_named("T3");
+        app.app.world.get_mut::<Focusable>(t3).unwrap().block();
+        let cycle = |app: &mut NavEcsMock, direction| {
+            let visited = (0..6).map(|_| {
+                app.run_request(NavRequest::ScopeMove(direction));
+                app.currently_focused().to_owned()
+            });
+            visited.collect::<Vec<_>>()
+        };
+        let visited = cycle(&mut app, Next);
+        assert_eq!(visited, ["T2", "T4", "T5", "T1", "T2", "T4"]);
+        let visited = cycle(&mut app, Previous);
+        assert_eq!(visited, ["T2", "T1", "T5", "T4", "T2", "T1"]);
+
+        // Without wrapping, the edge is the last non-blocked element.
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().wrapping = false;
+        let visited = cycle(&mut app, Next);
+        assert_eq!(visited, ["T2", "T4", "T5", "T5", "T5", "T5"]);
+
+        // Cycling with a single non-blocked element keeps the focus.
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().wrapping = true;
+        for name in ["T1", "T2", "T4"] {
+            let entity = app.entity_named(name);
+            app.app.world.get_mut::<Focusable>(entity).unwrap().block();
+        }
+        let events = app.run_request(NavRequest::ScopeMove(Next));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "T5");
+    }
+
+    #[test]
+    fn scope_order() {
+        use crate::resolve::{NavDirectionality, NavOrder};
+        use bevy::ecs::system::SystemState;
+        use events::ScopeDirection::Next;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("A"),
+            focusable("B"),
+            focusable_to("C"[focusable("C1")]),
+        ]);
+        let root = app.entity_named("Root");
+        let mut setting = app.app.world.get_mut::<MenuSetting>(root).unwrap();
+        setting.scope = true;
+        setting.wrapping = true;
+        setting.scope_stays_on_tabs = true;
+        let order = |app: &mut NavEcsMock| {
+            let mut state = SystemState::<(NavOrder, Query<&Name>)>::new(&mut app.app.world);
+            let (order, names) = state.get(&app.app.world);
+            let order = order.scope_order(root).into_iter();
+            order
+                .map(|e| names.get(e).unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(order(&mut app), ["A", "B", "C"]);
+
+        // Tab visits A, B, C, A.
+        let mut visited = vec![app.currently_focused().to_owned()];
+        for _ in 0..3 {
+            app.run_request(NavRequest::ScopeMove(Next));
+            visited.push(app.currently_focused().to_owned());
+        }
+        assert_eq!(visited, ["A", "B", "C", "A"]);
+
+        app.app.insert_resource(NavDirectionality::Rtl);
+        assert_eq!(order(&mut app), ["C", "B", "A"]);
+        app.run_request(NavRequest::ScopeMove(Next));
+        assert_eq!(app.currently_focused(), "C");
+    }
+
+    #[test]
+    fn scope_edge() {
+        use events::ScopeDirection::{Next, Previous};
+        let mut app = NavEcsMock::new(spawn_hierarchy![prioritized("Tab1"), focusable("Tab2"),]);
+        let root = app.entity_named("Root");
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().scope = true;
+
+        app.run_request(NavRequest::ScopeMove(Next));
+        assert_eq!(app.currently_focused(), "Tab2");
+        let events = app.run_request(NavRequest::ScopeMove(Next));
+        assert!(
+            matches!(
+                events[..],
+                [NavEvent::ScopeEdge { menu, direction: Next }] if menu == root
+            ),
+            "{events:#?}"
+        );
+        assert_eq!(app.currently_focused(), "Tab2");
+
+        // The root menu isn't in a scope menu, there is nothing to do.
+        app.app.world.get_mut::<MenuSetting>(root).unwrap().scope = false;
+        let events = app.run_request(NavRequest::ScopeMove(Previous));
+        assert!(
+            matches!(events[..], [NavEvent::NoChanges { .. }]),
+            "{events:#?}"
+        );
+    }
+
+    #[test]
+    fn refresh_restructured_menu() {
+        use bevy::ecs::system::Command;
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+        ]);
+        let menu = app.entity_named("Left Menu");
+        app.kill_named("L1");
+        let l3 = app
+            .app
+            .world
+            .spawn((Name::new("L3"), Focusable::new().prioritized()));
+        let l3 = l3.id();
+        app.app.world.entity_mut(menu).add_child(l3);
+        RefreshMenu(menu).apply(&mut app.app.world);
+        assert_eq!(app.state_of("L3"), FocusState::Prioritized);
+        assert_eq!(app.state_of("L2"), FocusState::Inert);
+
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["L3", "Left"]);
+
+        // The menu is focused, nothing changes.
+        let l2 = app.entity_named("L2");
+        app.app
+            .world
+            .entity_mut(l2)
+            .insert(Focusable::new().prioritized());
+        RefreshMenu(menu).apply(&mut app.app.world);
+        let tree = app.app.world.get::<resolve::TreeMenu>(menu).unwrap();
+        assert_eq!(tree.active_child, l3);
+    }
+
+    #[test]
+    fn set_active_child() {
+        use bevy::ecs::system::{Command, SystemState};
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+            focusable("Right"),
+        ]);
+        let [menu, l1, l2, right] = ["Left Menu", "L1", "L2", "Right"].map(|n| app.entity_named(n));
+        let mut active = SystemState::<ActiveChildren>::new(&mut app.app.world);
+        let active_child = |app: &NavEcsMock, active: &mut SystemState<ActiveChildren>| {
+            active.get(&app.app.world).of(menu)
+        };
+        assert_eq!(active_child(&app, &mut active), Some(l1));
+
+        // Not a focusable of the menu, rejected.
+        SetActiveChild { menu, child: right }.apply(&mut app.app.world);
+        assert_eq!(active_child(&app, &mut active), Some(l1));
+
+        SetActiveChild { menu, child: l2 }.apply(&mut app.app.world);
+        assert_eq!(active_child(&app, &mut active), Some(l2));
+        assert_eq!(app.state_of("L2"), FocusState::Prioritized);
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["L2", "Left"]);
+
+        // The menu is focused, rejected.
+        SetActiveChild { menu, child: l1 }.apply(&mut app.app.world);
+        assert_eq!(active_child(&app, &mut active), Some(l2));
+        assert_eq!(app.currently_focused(), "L2");
+
+        // A blocked previous active child stays blocked.
+        app.run_focus_on("Right");
+        app.app.world.get_mut::<Focusable>(l2).unwrap().block();
+        SetActiveChild { menu, child: l1 }.apply(&mut app.app.world);
+        assert_eq!(active_child(&app, &mut active), Some(l1));
+        assert_eq!(app.state_of("L1"), FocusState::Prioritized);
+        assert_eq!(app.state_of("L2"), FocusState::Blocked);
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn wrap_to_dormant() {
+        use crate::resolve::{Rect, ScreenBoundaries, UiProjectionQuery};
+        use events::Direction::East;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        app.insert_resource(ScreenBoundaries {
+            position: Vec2::ZERO,
+            screen_edge: Rect {
+                min: Vec2::ZERO,
+                max: Vec2::new(300.0, 200.0),
+            },
+            scale: 1.0,
+        });
+        let setting = MenuSetting::new().wrapping_to_dormant();
+        let menu = app.world.spawn((setting, MenuBuilder::Root)).id();
+        let grid = [
+            ("A", 50.0, 50.0),
+            ("B", 150.0, 50.0),
+            ("C", 250.0, 50.0),
+            ("D", 50.0, 150.0),
+            ("E", 150.0, 150.0),
+            ("F", 250.0, 150.0),
+        ];
+        for (name, x, y) in grid {
+            let focusable = if name == "A" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "A");
+
+        app.run_request(NavRequest::Move(East));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C");
+        // Wraps to "B", the last focused of the row, rather than "A".
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "B");
+
+        app.app
+            .world
+            .get_mut::<MenuSetting>(menu)
+            .unwrap()
+            .wrap_to_dormant = false;
+        app.run_request(NavRequest::Move(East));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "A");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn snake_wrap() {
+        use crate::resolve::{Rect, ScreenBoundaries, UiProjectionQuery};
+        use events::Direction::{East, North, South, West};
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        app.insert_resource(ScreenBoundaries {
+            position: Vec2::ZERO,
+            screen_edge: Rect {
+                min: Vec2::ZERO,
+                max: Vec2::new(300.0, 300.0),
+            },
+            scale: 1.0,
+        });
+        let menu = app
+            .world
+            .spawn((MenuSetting::new().snake_wrap(), MenuBuilder::Root));
+        let menu = menu.id();
+        let names = ["A", "B", "C", "D", "E", "F", "G", "H", "I"];
+        for (i, name) in names.into_iter().enumerate() {
+            let focusable = if name == "A" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let (x, y) = ((i % 3) as f32 * 100.0 + 50.0, (i / 3) as f32 * 100.0 + 50.0);
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "A");
+
+        // A B C
+        // D E F
+        // G H I
+        let mut visited = Vec::new();
+        for _ in 0..9 {
+            app.run_request(NavRequest::Move(East));
+            visited.push(app.currently_focused().to_owned());
+        }
+        assert_eq!(visited, ["B", "C", "D", "E", "F", "G", "H", "I", "A"]);
+
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "I");
+        app.run_focus_on("D");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "C");
+
+        app.run_focus_on("G");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "B");
+        app.run_focus_on("A");
+        app.run_request(NavRequest::Move(North));
+        assert_eq!(app.currently_focused(), "I");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn nav_links_override_spatial_moves() {
+        use crate::resolve::UiProjectionQuery;
+        use events::Direction::{East, South, West};
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        // A B
+        // C D
+        let grid = [
+            ("A", 50.0, 50.0),
+            ("B", 150.0, 50.0),
+            ("C", 50.0, 150.0),
+            ("D", 150.0, 150.0),
+        ];
+        for (name, x, y) in grid {
+            let focusable = if name == "A" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, y, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        let outside = app.world.spawn(Focusable::new()).id();
+        app.update();
+        let mut app = NavEcsMock { app };
+        let (a, d) = (app.entity_named("A"), app.entity_named("D"));
+        let links = NavLinks::new().east(d).west(outside);
+        app.app.world.entity_mut(a).insert(links);
+        assert_eq!(app.currently_focused(), "A");
+
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "D");
+
+        // Directions without links use spatial navigation.
+        app.run_focus_on("A");
+        app.run_request(NavRequest::Move(South));
+        assert_eq!(app.currently_focused(), "C");
+
+        // Links outside of the menu are ignored.
+        app.run_focus_on("B");
+        app.run_focus_on("A");
+        let events = app.run_request(NavRequest::Move(West));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "A");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn nav_camera_selection() {
+        use crate::resolve::nav_camera;
+        use bevy::ui::camera_config::UiCameraConfig;
+
+        let camera = |order| Camera { order, ..default() };
+        let (first, second, third) = (camera(0), camera(1), camera(2));
+        let hidden = UiCameraConfig { show_ui: false };
+        let mut warned = false;
+
+        let cameras = [(&first, None, false), (&second, None, true)];
+        let selected = nav_camera(cameras, &mut warned).map(|c| c.order);
+        assert_eq!(selected, Some(1));
+        assert!(!warned);
+
+        let cameras = [(&first, Some(&hidden), false), (&second, None, false)];
+        let selected = nav_camera(cameras, &mut warned).map(|c| c.order);
+        assert_eq!(selected, Some(1));
+        assert!(!warned);
+
+        let cameras = [(&first, None, false), (&third, None, false)];
+        let selected = nav_camera(cameras, &mut warned).map(|c| c.order);
+        assert_eq!(selected, Some(0));
+        assert!(warned);
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn update_boundaries_from_nav_camera() {
+        use crate::resolve::{NavCamera, ScreenBoundaries};
+        use bevy::render::camera::Viewport;
+
+        let mut app = App::new();
+        app.add_systems(Update, systems::update_boundaries);
+        let camera = |order, size| {
+            let physical_size = UVec2::splat(size);
+            let viewport = Some(Viewport {
+                physical_size,
+                ..default()
+            });
+            Camera {
+                order,
+                viewport,
+                ..default()
+            }
+        };
+        app.world.spawn(camera(0, 100));
+        let nav_camera = app.world.spawn((camera(1, 200), NavCamera)).id();
+        let screen_edge = |app: &App| app.world.resource::<ScreenBoundaries>().screen_edge.max;
+
+        app.update();
+        assert_eq!(screen_edge(&app), Vec2::splat(200.0));
+
+        // Without `NavCamera`, the first camera is used again.
+        app.world.entity_mut(nav_camera).remove::<NavCamera>();
+        app.update();
+        assert_eq!(screen_edge(&app), Vec2::splat(100.0));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn rtl_mirrors_horizontal_moves() {
+        use crate::resolve::UiProjectionQuery;
+        use events::Direction::{East, West};
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new())
+            .insert_resource(NavDirectionality::Rtl);
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        for (name, x) in [("A", 50.0), ("B", 150.0), ("C", 250.0)] {
+            let focusable = if name == "B" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, 50.0, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "B");
+
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "A");
+
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "B");
+
+        *app.app.world.resource_mut::<NavDirectionality>() = NavDirectionality::Ltr;
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn move_by() {
+        use crate::resolve::UiProjectionQuery;
+        use bevy::math::IVec2;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        let menu = app
+            .world
+            .spawn((MenuSetting::new(), MenuBuilder::Root))
+            .id();
+        for (name, x) in [("A", 50.0), ("B", 150.0), ("C", 250.0), ("D", 350.0)] {
+            let focusable = if name == "A" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, 50.0, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "A");
+
+        let events = app.run_request(NavRequest::MoveBy(IVec2::new(2, 0)));
+        assert_expected_focus_change!(app, &events[..], ["A"], ["C"]);
+
+        // Stops at the edge of non-wrapping menus.
+        app.run_request(NavRequest::MoveBy(IVec2::new(5, 0)));
+        assert_eq!(app.currently_focused(), "D");
+
+        app.run_request(NavRequest::MoveBy(IVec2::new(-1, 0)));
+        assert_eq!(app.currently_focused(), "C");
+
+        let events = app.run_request(NavRequest::MoveBy(IVec2::new(0, 1)));
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+    }
+
+    // Entities spawned this frame don't have a `GlobalTransform` yet.
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn move_before_transform_propagation() {
+        use crate::resolve::UiProjectionQuery;
+        use events::Direction::East;
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        let row = app
+            .world
+            .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+                100.0, 0.0, 0.0,
+            )));
+        let row = row.id();
+        let spawn_child = |app: &mut App, name, x| {
+            let transform = Transform::from_xyz(x, 0.0, 0.0);
+            let bundle = (Name::new(name), Focusable::new(), transform);
+            let entity = app.world.spawn((bundle, GlobalTransform::default())).id();
+            app.world.entity_mut(row).add_child(entity);
+        };
+        spawn_child(&mut app, "Start", 0.0);
+        spawn_child(&mut app, "Far", 200.0);
+        spawn_child(&mut app, "Near", 100.0);
+        app.world.spawn((
+            Name::new("Flat"),
+            Focusable::new(),
+            Transform::from_xyz(150.0, 0.0, 0.0),
+            GlobalTransform::default(),
+        ));
+        let mut app = NavEcsMock { app };
+        app.run_focus_on("Start");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Flat");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Near");
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn no_wrap_first_move() {
+        use crate::resolve::UiProjectionQuery;
+        use events::Direction::{East, West};
+
+        let mut app = App::new();
+        app.add_plugins(GenericNavigationPlugin::<UiProjectionQuery>::new());
+        let setting = MenuSetting::new().wrapping().no_wrap_first_move();
+        let menu = app.world.spawn((setting, MenuBuilder::Root)).id();
+        for (name, x) in [("A", 50.0), ("B", 150.0), ("C", 250.0)] {
+            let focusable = if name == "C" {
+                Focusable::new().prioritized()
+            } else {
+                Focusable::new()
+            };
+            let transform = GlobalTransform::from_xyz(x, 50.0, 0.0);
+            let entity = app.world.spawn((Name::new(name), focusable, transform));
+            let entity = entity.id();
+            app.world.entity_mut(menu).add_child(entity);
+        }
+        app.update();
+        let mut app = NavEcsMock { app };
+        assert_eq!(app.currently_focused(), "C");
+
+        let events = app.run_request(NavRequest::Move(East));
+        assert!(
+            matches!(events[..], [NavEvent::NoChanges { .. }]),
+            "{events:#?}"
+        );
+        assert_eq!(app.currently_focused(), "C");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "A");
+
+        // Focus is re-established, the first move doesn't wrap again.
+        app.run_focus_on("C");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C");
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "A");
+
+        // Right-to-left, `East` wraps from the leftmost focusable.
+        app.app.insert_resource(NavDirectionality::Rtl);
+        app.run_focus_on("B");
+        app.run_focus_on("A");
+        let events = app.run_request(NavRequest::Move(East));
+        assert!(
+            matches!(events[..], [NavEvent::NoChanges { .. }]),
+            "{events:#?}"
+        );
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "C");
+        app.run_focus_on("A");
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "B");
+    }
+
+    #[test]
+    fn no_wrap_first_move_per_menu() {
+        use events::Direction::{East, West};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable_to("Tab1" [
+                prioritized("T1Left"),
+                focusable("T1Right"),
+            ]),
+            focusable_to("Tab2" [
+                focusable("T2Left"),
+                focusable("T2Right"),
+            ]),
+        ]);
+        app.place(&[("T1Left", 0.0), ("T1Right", 100.0)]);
+        let [root, tab1, tab2] = ["Root", "Tab1 Menu", "Tab2 Menu"].map(|n| app.entity_named(n));
+        let world = &mut app.app.world;
+        world.get_mut::<MenuSetting>(root).unwrap().scope = true;
+        let mut setting = world.get_mut::<MenuSetting>(tab1).unwrap();
+        *setting = setting.move_overflow_scopes();
+        let mut setting = world.get_mut::<MenuSetting>(tab2).unwrap();
+        *setting = setting.wrapping().no_wrap_first_move();
+        world.entity_mut(tab2).insert(NavStrategy::Sequential);
+
+        app.run_focus_on("T1Left");
+        app.run_request(NavRequest::Move(East));
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "T2Left");
+
+        // Focus moved in "Tab1", but this is the first move in "Tab2".
+        let events = app.run_request(NavRequest::Move(West));
+        assert!(
+            matches!(events[..], [NavEvent::NoChanges { .. }]),
+            "{events:#?}"
+        );
+        app.run_request(NavRequest::Move(West));
+        assert_eq!(app.currently_focused(), "T2Right");
+    }
+
+    #[test]
+    fn lock_until() {
+        use crate::resolve::LockReason::NavRequest as ByRequest;
+        use events::Direction::East;
+        use NavEvent::{Locked, Unlocked};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Right"),
+        ]));
+        app.place(&[("Initial", 0.0), ("Right", 100.0)]);
+        let events = app.run_request(NavRequest::LockUntil(UnlockWith::Cancel));
+        assert!(matches!(events[..], [Locked(ByRequest), ..]));
+        let lock = app.app.world.resource::<NavLock>();
+        assert_eq!(lock.unlock_request(), Some(UnlockWith::Cancel));
+
+        assert!(app.run_request(NavRequest::Move(East)).is_empty());
+        assert!(app.run_request(NavRequest::Action).is_empty());
+        assert_eq!(app.currently_focused(), "Initial");
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert!(matches!(events[..], [Unlocked(ByRequest), ..]));
+        assert!(!app.app.world.resource::<NavLock>().is_locked());
+        app.run_request(NavRequest::Move(East));
+        assert_eq!(app.currently_focused(), "Right");
+
+        // `Cancel` doesn't unlock a plain `Lock`, `Unlock` still unlocks `LockUntil`.
+        app.run_request(NavRequest::Lock);
+        assert!(app.run_request(NavRequest::Cancel).is_empty());
+        app.run_request(NavRequest::Unlock);
+        app.run_request(NavRequest::LockUntil(UnlockWith::Cancel));
+        let events = app.run_request(NavRequest::Unlock);
+        assert!(matches!(events[..], [Unlocked(ByRequest), ..]));
+        assert_eq!(app.app.world.resource::<NavLock>().unlock_request(), None);
+
+        // `UnlockWith::Action` is any action.
+        app.run_request(NavRequest::LockUntil(UnlockWith::Action));
+        let events = app.run_request(NavRequest::ActionFrom(InputSource::Keyboard));
+        assert!(matches!(events[..], [Unlocked(ByRequest), ..]));
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn lock_changed() {
+        use crate::resolve::LockReason::NavRequest as ByRequest;
+        use NavEvent::{LockChanged, Locked};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+        ]));
+        let events = app.run_request(NavRequest::Lock);
+        assert!(matches!(
+            events[..],
+            [Locked(ByRequest), LockChanged(Some(ByRequest))]
+        ));
+        let snapshot = snapshot_nav(&app.app.world);
+
+        let events = app.run_request(NavRequest::Unlock);
+        assert!(matches!(events.last(), Some(LockChanged(None))));
+
+        // Without any `NavRequest`.
+        restore_nav(&snapshot, &mut app.app.world).unwrap();
+        app.app.update();
+        let events: Vec<NavEvent> = receive_events(&app.app.world);
+        assert!(matches!(events[..], [LockChanged(Some(ByRequest))]));
+        app.app.update();
+        let events: Vec<NavEvent> = receive_events(&app.app.world);
+        assert!(events.is_empty());
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn snapshot_round_trip() {
+        use bevy::utils::HashMap;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("L1"),
+                focusable("L2"),
+            ]),
+        ]);
+        app.run_focus_on("L2");
+        let snapshot = snapshot_nav(&app.app.world);
+
+        app.run_focus_on("Initial");
+        restore_nav(&snapshot, &mut app.app.world).unwrap();
+        assert_eq!(app.currently_focused(), "L2");
+        assert_eq!(app.state_of("Left"), FocusState::Active);
+        assert_eq!(app.state_of("Initial"), FocusState::Inert);
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["L2", "Left"], ["Left"]);
+
+        // Spawning the snapshot in another world maps the menu references.
+        let (l2, left, left_menu) = (
+            app.entity_named("L2"),
+            app.entity_named("Left"),
+            app.entity_named("Left Menu"),
+        );
+        let mut world = World::new();
+        world.insert_resource(app.app.world.resource::<AppTypeRegistry>().clone());
+        let mut entity_map = HashMap::default();
+        snapshot
+            .write_to_world(&mut world, &mut entity_map)
+            .unwrap();
+        let menu = world
+            .get::<resolve::TreeMenu>(entity_map[&left_menu])
+            .unwrap();
+        assert_eq!(menu.active_child, entity_map[&l2]);
+        assert_eq!(menu.focus_parent, Some(entity_map[&left]));
+    }
+
+    #[test]
+    fn conditional_focusable() {
+        #[derive(Component)]
+        struct Continue;
+        #[derive(Resource)]
+        struct SaveFile;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("New game"),
+            focusable("Continue"),
+        ]));
+        let continue_button = app.entity_named("Continue");
+        app.app.world.entity_mut(continue_button).insert(Continue);
+        app.app
+            .add_conditional_focusable::<Continue, _>(resource_exists::<SaveFile>());
+        app.app.update();
+        assert_eq!(app.state_of("Continue"), FocusState::Blocked);
+        app.run_focus_on("Continue");
+        assert_eq!(app.currently_focused(), "New game");
+
+        app.app.insert_resource(SaveFile);
+        app.run_focus_on("Continue");
+        assert_eq!(app.currently_focused(), "Continue");
+    }
+
+    #[test]
+    fn focusable_enabled_in_state() {
+        #[derive(Component)]
+        struct Continue;
+        #[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        enum Save {
+            #[default]
+            None,
+            Exists,
+        }
+
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("New game"),
+            focusable("Continue"),
+        ]));
+        let continue_button = app.entity_named("Continue");
+        app.app.world.entity_mut(continue_button).insert(Continue);
+        app.app
+            .add_state::<Save>()
+            .focusable_enabled_in_state::<Continue, _>(Save::Exists);
+        app.app.update();
+        assert_eq!(app.state_of("Continue"), FocusState::Blocked);
+        app.run_focus_on("Continue");
+        assert_eq!(app.currently_focused(), "New game");
+
+        app.app
+            .world
+            .resource_mut::<NextState<Save>>()
+            .set(Save::Exists);
+        app.app.update();
+        app.run_focus_on("Continue");
+        assert_eq!(app.currently_focused(), "Continue");
+
+        app.app
+            .world
+            .resource_mut::<NextState<Save>>()
+            .set(Save::None);
+        app.app.update();
+        app.app.update();
+        assert_eq!(app.currently_focused(), "New game");
+        assert_eq!(app.state_of("Continue"), FocusState::Blocked);
+    }
+
     // ====
     // What happens when Focused element is killed
     // ====
@@ -2,7 +2,7 @@
 //!
 //! Enables user to specify their own marker to add to [`Focusable`]s within
 //! [menus](crate::menu::MenuSetting).
-use std::iter;
+use std::{iter, marker::PhantomData};
 
 use bevy::prelude::*;
 
@@ -11,9 +11,39 @@ use crate::{
     resolve::{self, Focusable, TreeMenu},
 };
 
+/// Tracks `T` markers added by the propagation systems, only those are
+/// removed when their [`Focusable`] leaves the [`NavMarker<T>`] menu.
+#[derive(Component)]
+pub(crate) struct Propagated<T>(PhantomData<T>);
+impl<T> Default for Propagated<T> {
+    fn default() -> Self {
+        Propagated(PhantomData)
+    }
+}
+
+/// `T` markers the user added themselves, rather than the propagation systems.
+type OwnMarkers<'w, 's, T> = Query<'w, 's, (), (With<T>, Without<Propagated<T>>)>;
+
+/// Insert `T` markers, tracking the ones not already added by the user
+/// with [`Propagated<T>`].
+fn insert_markers<T: Component>(
+    cmds: &mut Commands,
+    own_markers: &OwnMarkers<T>,
+    to_insert: Vec<(Entity, (T,))>,
+) {
+    let propagated = to_insert
+        .iter()
+        .filter(|(e, _)| !own_markers.contains(*e))
+        .map(|(e, _)| (*e, Propagated::<T>::default()))
+        .collect::<Vec<_>>();
+    cmds.insert_or_spawn_batch(propagated);
+    cmds.insert_or_spawn_batch(to_insert);
+}
+
 pub(crate) fn mark_new_menus<T: Component + Clone>(
     mut cmds: Commands,
     new_markers: Query<(Entity, &NavMarker<T>), Added<TreeMenu>>,
+    own_markers: OwnMarkers<T>,
     children: resolve::ChildQueries,
 ) {
     let mut to_insert = Vec::new();
@@ -22,12 +52,13 @@ pub(crate) fn mark_new_menus<T: Component + Clone>(
         let menu_children = children.focusables_of(new_menu);
         to_insert.extend(menu_children.into_iter().zip(repeat_marker));
     }
-    cmds.insert_or_spawn_batch(to_insert);
+    insert_markers(&mut cmds, &own_markers, to_insert);
 }
 pub(crate) fn mark_new_focusables<T: Component + Clone>(
     mut cmds: Commands,
     new_focusables: Query<Entity, Added<Focusable>>,
     markers: Query<&NavMarker<T>, With<TreeMenu>>,
+    own_markers: OwnMarkers<T>,
     queries: resolve::NavQueries,
 ) {
     let mut to_insert = Vec::new();
@@ -42,5 +73,44 @@ pub(crate) fn mark_new_focusables<T: Component + Clone>(
         };
         to_insert.push((new_focusable, (marker,)));
     }
-    cmds.insert_or_spawn_batch(to_insert);
+    insert_markers(&mut cmds, &own_markers, to_insert);
+}
+/// Add or remove the `T` marker of [`Focusable`]s moved to a different menu,
+/// so that only `Focusable`s within a [`NavMarker<T>`] menu have it.
+///
+/// Moving any entity updates the `Focusable`s in its descendants, since
+/// they may have been moved with it. Markers the user added themselves
+/// are never removed.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn mark_moved_focusables<T: Component + Clone>(
+    mut cmds: Commands,
+    moved: Query<Entity, Changed<Parent>>,
+    mut unparented: RemovedComponents<Parent>,
+    focusables: Query<(Ref<Focusable>, Has<Propagated<T>>)>,
+    children: Query<&Children>,
+    markers: Query<&NavMarker<T>, With<TreeMenu>>,
+    own_markers: OwnMarkers<T>,
+    queries: resolve::NavQueries,
+) {
+    let mut to_insert = Vec::new();
+    let roots = moved.iter().chain(unparented.read());
+    let descendants = roots.flat_map(|e| iter::once(e).chain(children.iter_descendants(e)));
+    for focusable in descendants {
+        // Newly added focusables are handled in `mark_new_focusables`.
+        let Ok((focus, propagated)) = focusables.get(focusable) else {
+            continue;
+        };
+        if focus.is_added() {
+            continue;
+        }
+        let containing_menu = resolve::parent_menu(focusable, &queries);
+        match containing_menu.and_then(|(menu, _, _)| markers.get(menu).ok()) {
+            Some(marker) => to_insert.push((focusable, (marker.0.clone(),))),
+            None if propagated => {
+                cmds.entity(focusable).remove::<(T, Propagated<T>)>();
+            }
+            None => {}
+        }
+    }
+    insert_markers(&mut cmds, &own_markers, to_insert);
 }
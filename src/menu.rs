@@ -3,9 +3,13 @@
 use std::borrow::Cow;
 
 use bevy::core::Name;
-use bevy::ecs::{entity::Entity, prelude::Component};
+use bevy::ecs::entity::{Entity, EntityMapper, MapEntities};
+use bevy::ecs::prelude::Component;
 #[cfg(feature = "bevy_reflect")]
-use bevy::{ecs::reflect::ReflectComponent, reflect::Reflect};
+use bevy::{
+    ecs::reflect::{ReflectComponent, ReflectMapEntities},
+    reflect::Reflect,
+};
 
 /// Add this component to a menu entity so that all [`Focusable`]s
 /// within that menus gets added the `T` component automatically.
@@ -19,7 +23,11 @@ pub struct NavMarker<T: Component>(pub T);
 /// Note that `MenuBuilder` is replaced by a private component when encoutered.
 #[doc(alias = "NavMenu")]
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, MapEntities)
+)]
 pub enum MenuBuilder {
     /// Create a menu as reachable from a [`Focusable`]
     /// with a [`Name`] component.
@@ -101,6 +109,13 @@ impl bevy::prelude::FromWorld for MenuBuilder {
         Self::Root
     }
 }
+impl MapEntities for MenuBuilder {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        if let MenuBuilder::EntityParent(parent) = self {
+            *parent = entity_mapper.get_or_reserve(*parent);
+        }
+    }
+}
 impl TryFrom<&MenuBuilder> for Option<Entity> {
     type Error = ();
     fn try_from(value: &MenuBuilder) -> Result<Self, Self::Error> {
@@ -112,6 +127,74 @@ impl TryFrom<&MenuBuilder> for Option<Entity> {
     }
 }
 
+/// Make a [`Focusable`] member of the menu on the given entity,
+/// regardless of its position in the bevy hierarchy.
+///
+/// By default, a [`Focusable`] belongs to the closest [`MenuSetting`]
+/// among its ancestors. Use `MenuMember` when layout forces a different
+/// parenting, for example with a detached tooltip button.
+///
+/// The `Entity` must have a [`MenuSetting`] component, otherwise
+/// the hierarchy is used as if there were no `MenuMember`.
+///
+/// # Important
+///
+/// You must ensure this doesn't create a cycle. Eg: the menu `Entity`
+/// shouldn't be reachable from the `Focusable` with this component
+/// (see [`MenuBuilder`]). A `MenuMember` pointing to the menu
+/// the [`Focusable`] itself leads to is ignored.
+///
+/// [`Focusable`]: crate::prelude::Focusable
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, MapEntities)
+)]
+pub struct MenuMember(pub Entity);
+impl bevy::prelude::FromWorld for MenuMember {
+    /// This shouldn't be considered a good "default", this only exists
+    /// to make possible `ReflectComponent` derive.
+    fn from_world(_: &mut bevy::prelude::World) -> Self {
+        Self(Entity::PLACEHOLDER)
+    }
+}
+impl MapEntities for MenuMember {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.0 = entity_mapper.get_or_reserve(self.0);
+    }
+}
+
+/// How [`NavRequest::Move`] and [`NavRequest::ScopeMove2d`] pick the next
+/// focused element within the menu on the same entity.
+///
+/// Add this component next to a [`MenuSetting`] to mix navigation methods
+/// in a single app, for example spatial navigation in an inventory grid
+/// and index-based navigation in a list.
+///
+/// [`NavRequest::Move`]: crate::prelude::NavRequest::Move
+/// [`NavRequest::ScopeMove2d`]: crate::prelude::NavRequest::ScopeMove2d
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub enum NavStrategy {
+    /// Use the [`MenuNavigationStrategy`] of the navigation plugin,
+    /// the default, as if there was no `NavStrategy`.
+    ///
+    /// [`MenuNavigationStrategy`]: crate::prelude::MenuNavigationStrategy
+    #[default]
+    Global,
+
+    /// Move to the next or previous [`Focusable`] in hierarchy order,
+    /// like in a list.
+    ///
+    /// South and east go to the next focusable, north and west go to the
+    /// previous one. `NorthWest` and `SouthEast` follow the same rule,
+    /// while `NorthEast` and `SouthWest` do nothing.
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    Sequential,
+}
+
 /// A menu that isolate children [`Focusable`]s from other focusables
 /// and specify navigation method within itself.
 ///
@@ -125,6 +208,7 @@ impl TryFrom<&MenuBuilder> for Option<Entity> {
 ///   when the focused element is a [`Focusable`] nested within this `MenuSetting`
 ///   will move cursor within this menu.
 ///   See [`MenuSetting::scope`].
+/// * Use a different navigation method within this menu, see [`NavStrategy`].
 /// * Specify _submenus_ and specify from where those submenus are reachable.
 /// * Specify which entity will be the parents of this [`MenuSetting`].
 ///   See [`MenuBuilder`].
@@ -164,7 +248,11 @@ impl TryFrom<&MenuBuilder> for Option<Entity> {
 /// [`NavRequest`]: crate::prelude::NavRequest
 #[doc(alias = "NavMenu")]
 #[derive(Clone, Default, Component, Debug, Copy, PartialEq)]
-#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, MapEntities)
+)]
 pub struct MenuSetting {
     /// Whether to wrap navigation.
     ///
@@ -180,15 +268,146 @@ pub struct MenuSetting {
     ///
     /// [`NavRequest::ScopeMove`]: crate::prelude::NavRequest::ScopeMove
     pub scope: bool,
+
+    /// Whether to immediately activate the focusable of this menu
+    /// when entering it with [`NavRequest::Action`] if it is the only one.
+    ///
+    /// If the single focusable leads to a submenu, the focus goes into
+    /// that submenu, otherwise the focusable is _activated_, as if
+    /// another [`NavRequest::Action`] was sent.
+    ///
+    /// [`NavRequest::Action`]: crate::prelude::NavRequest::Action
+    pub auto_activate_single: bool,
+
+    /// When [`wrapping`], whether to focus the last focused element
+    /// of the row or column wrapped to, rather than the element at its edge.
+    ///
+    /// The row or column is defined by the [`MenuNavigationStrategy::wrap_region`].
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`MenuNavigationStrategy::wrap_region`]: crate::prelude::MenuNavigationStrategy::wrap_region
+    pub wrap_to_dormant: bool,
+
+    /// Where to go on [`NavRequest::Cancel`], instead of the [`Focusable`]
+    /// this menu is reachable from.
+    ///
+    /// The target must be a non-blocked [`Focusable`] outside of this menu
+    /// and its submenus, otherwise `Cancel` behaves as if this was `None`.
+    ///
+    /// [`NavRequest::Cancel`]: crate::prelude::NavRequest::Cancel
+    /// [`Focusable`]: crate::prelude::Focusable
+    pub cancel_to: Option<Entity>,
+
+    /// When [`wrapping`], whether the first [`NavRequest::Move`] after focus
+    /// is established, for example by entering this menu, never wraps.
+    ///
+    /// This prevents a direction held while the menu appears from
+    /// immediately wrapping to the other side, the player has to
+    /// send a second move to wrap.
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`NavRequest::Move`]: crate::prelude::NavRequest::Move
+    pub no_wrap_first_move: bool,
+
+    /// When [`scope`], whether a [`NavRequest::ScopeMove`] from the tab bar
+    /// keeps focus on the newly focused tab.
+    ///
+    /// By default, a successful `ScopeMove` enters the submenu of the newly
+    /// focused tab, as with [`NavRequest::Action`], down to its prioritized
+    /// focusable. With this, focus only goes into the new tab's content when
+    /// it was already within the content of the previous tab.
+    ///
+    /// [`scope`]: Self::scope
+    /// [`NavRequest::ScopeMove`]: crate::prelude::NavRequest::ScopeMove
+    /// [`NavRequest::Action`]: crate::prelude::NavRequest::Action
+    pub scope_stays_on_tabs: bool,
+
+    /// When [`wrapping`], whether moving past the end of a row continues
+    /// at the start of the next row, rather than the start of the same row.
+    ///
+    /// Moving east from the last column focuses the first column of the
+    /// row below, and east from the very last element focuses the very first.
+    /// Vertical moves snake through columns the same way.
+    /// Like in text grids or on-screen keyboards.
+    ///
+    /// The next row is found by moving from the start of the wrapped row
+    /// with the [`MenuNavigationStrategy`], diagonal moves wrap normally.
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`MenuNavigationStrategy`]: crate::prelude::MenuNavigationStrategy
+    pub snake_wrap: bool,
+
+    /// Whether a [`NavRequest::Move`] that can't move within this menu
+    /// becomes a [`NavRequest::ScopeMove`].
+    ///
+    /// `South` and `East` become [`ScopeDirection::Next`], `North`
+    /// and `West` become [`ScopeDirection::Previous`], diagonals are
+    /// unchanged. For example, pressing down at the bottom of a list
+    /// within a tab goes to the next tab. Past the last tab, this
+    /// emits a [`NavEvent::ScopeEdge`] as a `ScopeMove` would.
+    ///
+    /// [`NavEvent::ScopeEdge`]: crate::prelude::NavEvent::ScopeEdge
+    /// [`NavRequest::Move`]: crate::prelude::NavRequest::Move
+    /// [`NavRequest::ScopeMove`]: crate::prelude::NavRequest::ScopeMove
+    /// [`ScopeDirection::Next`]: crate::events::ScopeDirection::Next
+    /// [`ScopeDirection::Previous`]: crate::events::ScopeDirection::Previous
+    pub move_overflow_scopes: bool,
+
+    /// Whether this menu forgets its last focused element when the focus
+    /// leaves it, entering it again focuses its first [`Focusable`].
+    ///
+    /// By default, entering a menu again focuses the element that was
+    /// focused when leaving it.
+    ///
+    /// [`Focusable`]: crate::prelude::Focusable
+    pub forget_on_leave: bool,
+
+    /// Whether [`NavRequest::Move`] within this menu is ignored until the
+    /// menu is _engaged_ with a [`NavRequest::Action`].
+    ///
+    /// While focus is in a non-engaged menu, `Move` does nothing and the first
+    /// `Action` engages the menu instead of activating the focused element.
+    /// Pointer clicks, [`NavRequest::ActionFrom`]`(`[`InputSource::Pointer`]`)`,
+    /// are the exception: they engage the menu and activate the clicked element.
+    /// [`NavRequest::Cancel`] disengages an engaged menu without changing
+    /// focus, leaving the menu also disengages it. Both emit
+    /// a [`NavEvent::Engaged`]. Useful for complex widgets, such as
+    /// a 2D canvas or a scroll area within a list.
+    ///
+    /// [`NavRequest::Move`]: crate::prelude::NavRequest::Move
+    /// [`NavRequest::Action`]: crate::prelude::NavRequest::Action
+    /// [`NavRequest::Cancel`]: crate::prelude::NavRequest::Cancel
+    /// [`NavRequest::ActionFrom`]: crate::prelude::NavRequest::ActionFrom
+    /// [`InputSource::Pointer`]: crate::prelude::InputSource::Pointer
+    /// [`NavEvent::Engaged`]: crate::prelude::NavEvent::Engaged
+    pub engage_required: bool,
+}
+impl MapEntities for MenuSetting {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        if let Some(target) = &mut self.cancel_to {
+            *target = entity_mapper.get_or_reserve(*target);
+        }
+    }
 }
 impl MenuSetting {
-    pub(crate) fn bound(&self) -> bool {
+    /// Whether navigation stops at the edges of this menu,
+    /// the opposite of [`MenuSetting::is_wrapping`].
+    pub fn is_bound(&self) -> bool {
         !self.wrapping
     }
+    /// Whether navigation wraps around this menu, see [`wrapping`].
+    ///
+    /// [`wrapping`]: Self::wrapping
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
     pub(crate) fn is_2d(&self) -> bool {
         !self.is_scope()
     }
-    pub(crate) fn is_scope(&self) -> bool {
+    /// Whether this is a scope menu, see [`scope`].
+    ///
+    /// [`scope`]: Self::scope
+    pub fn is_scope(&self) -> bool {
         self.scope
     }
     /// Create a new non-wrapping, non-scopped [`MenuSetting`],
@@ -199,6 +418,21 @@ impl MenuSetting {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Create a [`MenuSetting`] with the given [`wrapping`] and [`scope`] values,
+    /// other fields have their default values.
+    ///
+    /// This is equivalent to `MenuSetting::new().wrapping().scope()`
+    /// when both are `true`, useful when the flags come from data.
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`scope`]: Self::scope
+    pub fn from_flags(wrapping: bool, scope: bool) -> Self {
+        Self {
+            wrapping,
+            scope,
+            ..Self::default()
+        }
+    }
     /// Set [`wrapping`] to true.
     ///
     /// [`wrapping`]: Self::wrapping
@@ -213,4 +447,71 @@ impl MenuSetting {
         self.scope = true;
         self
     }
+    /// Set [`wrapping`] and [`wrap_to_dormant`] to true.
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`wrap_to_dormant`]: Self::wrap_to_dormant
+    pub fn wrapping_to_dormant(mut self) -> Self {
+        self.wrapping = true;
+        self.wrap_to_dormant = true;
+        self
+    }
+    /// Set [`wrapping`] and [`snake_wrap`] to true.
+    ///
+    /// [`wrapping`]: Self::wrapping
+    /// [`snake_wrap`]: Self::snake_wrap
+    pub fn snake_wrap(mut self) -> Self {
+        self.wrapping = true;
+        self.snake_wrap = true;
+        self
+    }
+    /// Set [`move_overflow_scopes`] to true.
+    ///
+    /// [`move_overflow_scopes`]: Self::move_overflow_scopes
+    pub fn move_overflow_scopes(mut self) -> Self {
+        self.move_overflow_scopes = true;
+        self
+    }
+    /// Set [`forget_on_leave`] to true.
+    ///
+    /// [`forget_on_leave`]: Self::forget_on_leave
+    pub fn forget_on_leave(mut self) -> Self {
+        self.forget_on_leave = true;
+        self
+    }
+    /// Set [`engage_required`] to true.
+    ///
+    /// [`engage_required`]: Self::engage_required
+    pub fn engage_required(mut self) -> Self {
+        self.engage_required = true;
+        self
+    }
+    /// Set [`no_wrap_first_move`] to true.
+    ///
+    /// [`no_wrap_first_move`]: Self::no_wrap_first_move
+    pub fn no_wrap_first_move(mut self) -> Self {
+        self.no_wrap_first_move = true;
+        self
+    }
+    /// Set [`scope_stays_on_tabs`] to true.
+    ///
+    /// [`scope_stays_on_tabs`]: Self::scope_stays_on_tabs
+    pub fn scope_stays_on_tabs(mut self) -> Self {
+        self.scope_stays_on_tabs = true;
+        self
+    }
+    /// Set [`cancel_to`] to `target`.
+    ///
+    /// [`cancel_to`]: Self::cancel_to
+    pub fn cancel_to(mut self, target: Entity) -> Self {
+        self.cancel_to = Some(target);
+        self
+    }
+    /// Set [`auto_activate_single`] to true.
+    ///
+    /// [`auto_activate_single`]: Self::auto_activate_single
+    pub fn auto_activate_single(mut self) -> Self {
+        self.auto_activate_single = true;
+        self
+    }
 }
@@ -0,0 +1,107 @@
+//! Declare a whole navigation tree at once with [`nav_tree!`].
+//!
+//! [`nav_tree!`]: crate::nav_tree!
+use bevy::ecs::{entity::Entity, system::EntityCommands};
+use bevy::hierarchy::BuildChildren;
+
+/// Add `child` to `parent`, used by [`nav_tree!`] so that users
+/// do not need to import [`BuildChildren`].
+///
+/// [`nav_tree!`]: crate::nav_tree!
+#[doc(hidden)]
+pub fn add_child(mut parent: EntityCommands, child: Entity) {
+    parent.add_child(child);
+}
+
+/// Spawn menus, [`Focusable`]s and other UI nodes with `commands`,
+/// linking submenus to their parent [`Focusable`] through local identifiers.
+///
+/// Each node is `identifier: kind(arguments)`, optionally followed by
+/// `[ children, ... ]`, spawned as children of the node in the UI hierarchy.
+/// `kind` is one of:
+/// - `menu(setting, bundle)`: a menu with the [`MenuSetting`] `setting`
+///   and `bundle`, usually a `NodeBundle`. It is a [`MenuBuilder::Root`] menu,
+///   unless followed by `from parent`, in which case it is reachable from the
+///   `parent` identifier, as with [`MenuBuilder::EntityParent`].
+/// - `focusable(bundle)`: `bundle` with a [`Focusable`].
+/// - `prioritized(bundle)`: `bundle` with a [`Focusable::prioritized`].
+/// - `node(bundle)`: `bundle` without any navigation components.
+///
+/// Every identifier is bound to the spawned [`Entity`] after the macro,
+/// so you can add the nodes to the rest of your UI. All entities are spawned
+/// before any `from parent` is resolved, so a menu may be declared before
+/// the [`Focusable`] it is reachable from. Using an undeclared identifier,
+/// or `from` on something else than a menu, is a compile error.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_navigation::{nav_tree, prelude::*};
+///
+/// fn setup(mut commands: Commands) {
+///     nav_tree!(commands,
+///         main: menu(MenuSetting::new(), NodeBundle::default()) [
+///             play: prioritized(ButtonBundle::default()),
+///             options: focusable(ButtonBundle::default()),
+///         ],
+///         options_menu: menu(MenuSetting::new().wrapping(), NodeBundle::default()) from options [
+///             volume: focusable(ButtonBundle::default()) [
+///                 volume_text: node(TextBundle::from_section("Volume", default())),
+///             ],
+///             back: focusable(ButtonBundle::default()),
+///         ],
+///     );
+///     commands.entity(back).insert(Focusable::cancel());
+/// }
+/// # App::new().add_systems(Startup, setup);
+/// ```
+///
+/// [`Focusable`]: crate::prelude::Focusable
+/// [`Focusable::prioritized`]: crate::prelude::Focusable::prioritized
+/// [`MenuSetting`]: crate::prelude::MenuSetting
+/// [`MenuBuilder::Root`]: crate::prelude::MenuBuilder::Root
+/// [`MenuBuilder::EntityParent`]: crate::prelude::MenuBuilder::EntityParent
+#[macro_export]
+macro_rules! nav_tree {
+    (@declare $c:ident $(,)?) => {};
+    (@declare $c:ident,
+        $name:ident : $kind:ident $args:tt $(from $parent:ident)? $([ $($children:tt)* ])?
+        $(, $($rest:tt)*)?
+    ) => {
+        let $name = $c.spawn_empty().id();
+        // Most identifiers are only used for the tree structure.
+        let _ = $name;
+        $( $crate::nav_tree!(@declare $c, $($children)*); )?
+        $crate::nav_tree!(@declare $c, $($($rest)*)?);
+    };
+    (@build $c:ident, [$($p:ident)?] $(,)?) => {};
+    (@build $c:ident, [$($p:ident)?],
+        $name:ident : $kind:ident $args:tt $(from $parent:ident)? $([ $($children:tt)* ])?
+        $(, $($rest:tt)*)?
+    ) => {
+        $c.entity($name).insert($crate::nav_tree!(@bundle $kind $args $(from $parent)?));
+        $( $crate::__nav_tree_add_child($c.entity($p), $name); )?
+        $( $crate::nav_tree!(@build $c, [$name], $($children)*); )?
+        $crate::nav_tree!(@build $c, [$($p)?], $($($rest)*)?);
+    };
+    (@bundle menu ($setting:expr, $bundle:expr $(,)?)) => {
+        ($setting, $crate::prelude::MenuBuilder::Root, $bundle)
+    };
+    (@bundle menu ($setting:expr, $bundle:expr $(,)?) from $parent:ident) => {
+        ($setting, $crate::prelude::MenuBuilder::EntityParent($parent), $bundle)
+    };
+    (@bundle focusable ($bundle:expr $(,)?)) => {
+        ($crate::prelude::Focusable::new(), $bundle)
+    };
+    (@bundle prioritized ($bundle:expr $(,)?)) => {
+        ($crate::prelude::Focusable::new().prioritized(), $bundle)
+    };
+    (@bundle node ($bundle:expr $(,)?)) => {
+        $bundle
+    };
+    ($commands:ident, $($nodes:tt)*) => {
+        $crate::nav_tree!(@declare $commands, $($nodes)*);
+        $crate::nav_tree!(@build $commands, [], $($nodes)*);
+    };
+}
@@ -22,6 +22,8 @@
 //! * [`MenuNavigationStrategy::resolve_2d`]
 //! * [`resolve_scope`]
 //!
+//! The UI-agnostic parts of the algorithm live in [`crate::generic`].
+//!
 //! A trait [`MenuNavigationStrategy`] allows user-defined movements
 //! through a custom system parameter by implementing `resolve_2d`.
 //!
@@ -35,36 +37,53 @@
 //!
 //! [`listen_nav_requests`] uses a `ParamSet` to access the focusables immutably for
 //! navigation resolution and mutably for updating them with the new navigation state.
-use std::num::NonZeroUsize;
-
+use bevy::core::Name;
+use bevy::ecs::change_detection::{DetectChanges, DetectChangesMut};
+use bevy::ecs::entity::{EntityMapper, MapEntities};
 #[cfg(feature = "bevy_reflect")]
-use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::ecs::reflect::{ReflectComponent, ReflectMapEntities, ReflectResource};
+use bevy::ecs::schedule::InternedScheduleLabel;
 use bevy::hierarchy::{Children, Parent};
 use bevy::log::{debug, warn};
+#[cfg(debug_assertions)]
+use bevy::prelude::Added;
 use bevy::prelude::{Changed, FromWorld};
 #[cfg(feature = "bevy_reflect")]
 use bevy::reflect::Reflect;
+#[cfg(feature = "bevy_ui")]
+use bevy::render::camera::Camera;
+use bevy::time::Time;
+#[cfg(feature = "bevy_ui")]
+use bevy::utils::{FloatOrd, HashMap};
 use bevy::{
     ecs::{
         event::{EventReader, EventWriter},
-        prelude::{Commands, Component, Entity, ParamSet, Query, ResMut, With, Without},
+        prelude::{
+            Commands, Component, Entity, Local, ParamSet, Query, Res, ResMut, With, Without,
+        },
+        query::Has,
         system::{Resource, StaticSystemParam, SystemParam, SystemParamItem},
     },
-    math::Vec2,
+    math::{Vec2, Vec3Swizzles},
+    transform::components::GlobalTransform,
 };
 #[cfg(feature = "bevy_ui")]
 use bevy::{
-    math::Vec3Swizzles,
-    prelude::{GlobalTransform, Res},
-    utils::FloatOrd,
+    math::IVec2,
+    transform::components::Transform,
+    ui::{camera_config::UiCameraConfig, CalculatedClip, Node, Style},
 };
 
 use non_empty_vec::NonEmpty;
 
 use crate::{
     commands::set_focus_state,
-    events::{self, NavEvent, NavRequest},
-    menu::{MenuBuilder, MenuSetting},
+    events::{
+        self, ActiveTrail, CoalesceNavRequests, FrameNavSummary, InputSource, NavEvent, NavRequest,
+        UnlockWith,
+    },
+    generic::{resolve_scope, trim_common_tail},
+    menu::{MenuBuilder, MenuMember, MenuSetting, NavStrategy},
 };
 
 /// System parameter used to resolve movement and cycling focus updates.
@@ -90,6 +109,37 @@ pub trait MenuNavigationStrategy {
         cycles: bool,
         siblings: &'a [Entity],
     ) -> Option<&'a Entity>;
+
+    /// Which [`Entity`] in `candidates` to focus when nothing is focused yet
+    /// and no menu or [prioritized] focusable decides it.
+    ///
+    /// By default, `None`, letting the navigation system pick
+    /// an arbitrary focusable.
+    ///
+    /// [prioritized]: Focusable::prioritized
+    fn fallback_focus<'a>(&self, candidates: &'a [Entity]) -> Option<&'a Entity> {
+        let _ = candidates;
+        None
+    }
+
+    /// The entities of `siblings` in the same row or column as `wrapped_to`,
+    /// when [`resolve_2d`] wrapped to `wrapped_to` while moving in `direction`.
+    ///
+    /// With [`MenuSetting::wrap_to_dormant`], the last focused entity
+    /// of this region is focused instead of `wrapped_to`.
+    ///
+    /// By default, empty, always focusing `wrapped_to`.
+    ///
+    /// [`resolve_2d`]: Self::resolve_2d
+    fn wrap_region(
+        &self,
+        wrapped_to: Entity,
+        direction: events::Direction,
+        siblings: &[Entity],
+    ) -> Vec<Entity> {
+        let _ = (wrapped_to, direction, siblings);
+        Vec::new()
+    }
 }
 
 /// A rectangle to specify the [`ScreenBoundaries`],
@@ -119,17 +169,177 @@ pub struct ScreenBoundaries {
     pub scale: f32,
 }
 
+/// A user-defined rule to skip [`Focusable`]s during navigation.
+///
+/// This is checked in addition to [`FocusState::Blocked`],
+/// set it with the [`FocusableFilter`] resource.
+///
+/// It is implemented for all `Fn(Entity) -> bool` closures.
+pub trait NavFilter: Send + Sync + 'static {
+    /// Whether `entity` can be navigated to.
+    fn is_focusable(&self, entity: Entity) -> bool;
+}
+impl<F: Fn(Entity) -> bool + Send + Sync + 'static> NavFilter for F {
+    fn is_focusable(&self, entity: Entity) -> bool {
+        self(entity)
+    }
+}
+
+/// The [`NavFilter`] used by the navigation system to skip [`Focusable`]s.
+///
+/// The default filter accepts every [`Focusable`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy_ui_navigation::prelude::FocusableFilter;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct LockedSlot;
+///
+/// fn skip_locked_slots(mut commands: Commands, locked: Query<Entity, With<LockedSlot>>) {
+///     let locked: Vec<_> = locked.iter().collect();
+///     commands.insert_resource(FocusableFilter::new(move |e| !locked.contains(&e)));
+/// }
+/// ```
+#[derive(Resource)]
+pub struct FocusableFilter(Box<dyn NavFilter>);
+impl FocusableFilter {
+    /// Create a `FocusableFilter` that skips entities failing `filter`.
+    pub fn new(filter: impl NavFilter) -> Self {
+        Self(Box::new(filter))
+    }
+}
+impl Default for FocusableFilter {
+    fn default() -> Self {
+        Self::new(|_| true)
+    }
+}
+
+/// The reading direction of the UI.
+///
+/// With [`NavDirectionality::Rtl`], horizontal [`NavRequest::Move`]s
+/// and [`NavRequest::ScopeMove`]s are mirrored: `East` moves to the focusable
+/// on the left and [`ScopeDirection::Next`] to the previous one.
+/// This lets the same menus work for left-to-right and right-to-left languages.
+///
+/// Without this resource, the UI is left-to-right.
+///
+/// [`ScopeDirection::Next`]: events::ScopeDirection::Next
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub enum NavDirectionality {
+    /// Left-to-right, the default.
+    #[default]
+    Ltr,
+    /// Right-to-left.
+    Rtl,
+}
+
 #[derive(SystemParam)]
 pub(crate) struct ChildQueries<'w, 's> {
     children: Query<'w, 's, &'static Children>,
     is_focusable: Query<'w, 's, &'static Focusable>,
     is_menu: Query<'w, 's, With<MenuSetting>>,
+    members: Query<'w, 's, (Entity, &'static MenuMember)>,
+    pointer_only: Query<'w, 's, (), With<PointerOnly>>,
+    filter: Option<Res<'w, FocusableFilter>>,
+}
+
+/// The point close to which [`UiProjectionQuery`] picks the first focused
+/// [`Focusable`] when nothing else decides which one to focus.
+///
+/// When `None`, the default, this is the center of the [`ScreenBoundaries`].
+#[cfg(feature = "bevy_ui")]
+#[derive(Default, Debug, Clone, Copy, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct FallbackFocusPoint(pub Option<Vec2>);
+
+/// Ignore [`Focusable`]s fully clipped by an ancestor's `Overflow`
+/// when moving focus with [`UiProjectionQuery`].
+///
+/// Without it, a [`NavRequest::Move`] may focus a [`Focusable`] scrolled out
+/// of a scroll area, which is invisible. With it, the closest visible
+/// [`Focusable`] in the requested direction is focused instead.
+///
+/// This is opt-in, because checking clip rects has a cost.
+/// Add it to your app with `app.init_resource::<SkipClipped>()`.
+#[cfg(feature = "bevy_ui")]
+#[derive(Default, Debug, Clone, Copy, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct SkipClipped;
+
+/// Ignore [`Focusable`]s centered outside of the UI camera's viewport
+/// when moving focus with [`UiProjectionQuery`].
+///
+/// Useful for split-screen or windowed UIs. When the focused [`Focusable`]
+/// leaves the viewport, for example by scrolling, the nearest one in the
+/// viewport within the same menu is focused.
+///
+/// `viewport` is updated each frame from the [`NavCamera`] or the first
+/// camera showing UI. Without such camera, it is kept as is, by default `None`,
+/// which ignores no [`Focusable`].
+///
+/// This is opt-in, add it to your app with `app.init_resource::<SkipOffscreen>()`.
+#[cfg(feature = "bevy_ui")]
+#[derive(Default, Debug, Clone, Copy, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct SkipOffscreen {
+    /// The visible area, in logical pixels.
+    pub viewport: Option<bevy::math::Rect>,
+}
+
+/// The camera driving [`ScreenBoundaries`] and [`SkipOffscreen`].
+///
+/// Only needed when several cameras show UI, for example in split-screen
+/// or with multiple windows. Without `NavCamera`, the first camera
+/// showing UI is used.
+///
+/// There is a single `ScreenBoundaries` for all menus, the one of the
+/// `NavCamera`: per-camera boundaries aren't supported, since UI nodes
+/// aren't tied to a specific camera. In split-screen, menus shown by other
+/// cameras wrap according to the `NavCamera` viewport.
+#[cfg(feature = "bevy_ui")]
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct NavCamera;
+
+/// The camera navigation uses, among `cameras` showing UI, see [`NavCamera`].
+///
+/// Warns if several cameras show UI without a [`NavCamera`],
+/// only if `warned` is `false`, and then sets it.
+#[cfg(feature = "bevy_ui")]
+pub(crate) fn nav_camera<'a>(
+    cameras: impl IntoIterator<Item = (&'a Camera, Option<&'a UiCameraConfig>, bool)>,
+    warned: &mut bool,
+) -> Option<&'a Camera> {
+    let shows_ui =
+        |(_, config, _): &(_, Option<&UiCameraConfig>, _)| config.map_or(true, |c| c.show_ui);
+    let ui_cameras: Vec<_> = cameras.into_iter().filter(shows_ui).collect();
+    if let Some((camera, ..)) = ui_cameras.iter().find(|(.., is_nav_camera)| *is_nav_camera) {
+        return Some(camera);
+    }
+    if ui_cameras.len() > 1 && !*warned {
+        *warned = true;
+        warn!(
+            "Several cameras show UI, but none has a `NavCamera` component, \
+            using the first one for navigation. Add `NavCamera` to the camera \
+            navigation should use."
+        );
+    }
+    ui_cameras.first().map(|(camera, ..)| *camera)
 }
 
 /// System parameter for the default cursor navigation system.
 ///
 /// It uses the bevy [`GlobalTransform`] to compute relative positions
-/// and change focus to the correct entity.
+/// and change focus to the correct entity. Entities spawned in the current
+/// frame don't have their `GlobalTransform` computed yet, their position
+/// is then computed from their [`Transform`] and their ancestors' ones.
+///
+/// Focusables stacked at the same position are all navigable, the topmost
+/// one, with the highest `GlobalTransform` `z`, is preferred when moving
+/// to or initially focusing that position.
 /// It uses the [`ScreenBoundaries`] resource to compute screen boundaries
 /// and move the cursor accordingly when it reaches a screen border
 /// in a cycling menu.
@@ -137,7 +347,277 @@ pub(crate) struct ChildQueries<'w, 's> {
 #[derive(SystemParam)]
 pub struct UiProjectionQuery<'w, 's> {
     boundaries: Option<Res<'w, ScreenBoundaries>>,
+    fallback_point: Option<Res<'w, FallbackFocusPoint>>,
+    skip_clipped: Option<Res<'w, SkipClipped>>,
+    skip_offscreen: Option<Res<'w, SkipOffscreen>>,
     transforms: Query<'w, 's, &'static GlobalTransform>,
+    local_transforms: Query<'w, 's, &'static Transform>,
+    parents: Query<'w, 's, &'static Parent>,
+    magnets: Query<'w, 's, &'static FocusMagnet>,
+    clips: Query<'w, 's, (&'static Node, &'static CalculatedClip)>,
+}
+#[cfg(feature = "bevy_ui")]
+impl<'w, 's> UiProjectionQuery<'w, 's> {
+    /// The 2d position of `entity`, `None` if it has no [`GlobalTransform`].
+    ///
+    /// When the `GlobalTransform` is not computed yet, because `entity`
+    /// was spawned this frame, this composes the [`Transform`] of `entity`
+    /// with the ones of its ancestors.
+    pub fn position(&self, entity: Entity) -> Option<Vec2> {
+        let global = self.transforms.get(entity).ok()?;
+        if *global != GlobalTransform::IDENTITY {
+            return Some(global.translation().xy());
+        }
+        let Ok(local) = self.local_transforms.get(entity) else {
+            return Some(global.translation().xy());
+        };
+        let mut global = GlobalTransform::from(*local);
+        let mut current = entity;
+        while let Ok(parent) = self.parents.get(current) {
+            current = parent.get();
+            match self.transforms.get(current) {
+                Ok(parent) if *parent != GlobalTransform::IDENTITY => {
+                    global = *parent * global;
+                    break;
+                }
+                _ => {}
+            }
+            let Ok(parent) = self.local_transforms.get(current) else {
+                break;
+            };
+            global = GlobalTransform::from(*parent) * global;
+        }
+        Some(global.translation().xy())
+    }
+
+    /// Whether `entity` can be seen, see [`SkipClipped`] and [`SkipOffscreen`].
+    ///
+    /// Always `true` if neither resource exists.
+    pub fn is_visible(&self, entity: Entity) -> bool {
+        let viewport = self.skip_offscreen.as_ref().and_then(|s| s.viewport);
+        if let (Some(viewport), Some(position)) = (viewport, self.position(entity)) {
+            if !viewport.contains(position) {
+                return false;
+            }
+        }
+        if self.skip_clipped.is_none() {
+            return true;
+        }
+        let Ok((node, clip)) = self.clips.get(entity) else {
+            return true;
+        };
+        let Ok(transform) = self.transforms.get(entity) else {
+            return true;
+        };
+        let rect = node.logical_rect(transform);
+        // Also check the center, for zero-sized nodes.
+        !clip.clip.intersect(rect).is_empty() || clip.clip.contains(rect.center())
+    }
+
+    /// The `z` of the [`GlobalTransform`] of `entity`, `0` if it has none.
+    ///
+    /// In `bevy_ui`, nodes drawn on top of others have a higher `z`.
+    fn depth(&self, entity: Entity) -> f32 {
+        self.transforms
+            .get(entity)
+            .map_or(0.0, |t| t.translation().z)
+    }
+
+    /// The entity in `candidates` closest to `point`,
+    /// ignoring entities without a [`GlobalTransform`].
+    ///
+    /// Of stacked entities at the same position, the topmost one,
+    /// with the highest `z`, is picked.
+    pub fn nearest_to<'a>(&self, point: Vec2, candidates: &'a [Entity]) -> Option<&'a Entity> {
+        let with_distance = |entity: &'a Entity| {
+            let position = self.position(*entity)?;
+            let depth = FloatOrd(-self.depth(*entity));
+            Some((entity, (FloatOrd(position.distance_squared(point)), depth)))
+        };
+        let closest = candidates.iter().filter_map(with_distance);
+        closest
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(e, _)| e)
+    }
+
+    /// The visible entities in `siblings` in `direction` from `focused`,
+    /// with their score, best first.
+    ///
+    /// The score is the distance to `focused` divided by the [`FocusMagnet`]
+    /// strength, lower is better. When not wrapping, [`NavRequest::Move`]
+    /// focuses the first candidate. Of candidates with the same score,
+    /// such as stacked entities, the topmost one, with the highest `z`
+    /// of its [`GlobalTransform`], comes first.
+    ///
+    /// # Panics
+    ///
+    /// If `focused` or an entity of `siblings` has no [`GlobalTransform`].
+    pub fn candidates_2d(
+        &self,
+        focused: Entity,
+        direction: events::Direction,
+        siblings: &[Entity],
+    ) -> Vec<(Entity, f32)> {
+        let pos_of = |entity: Entity| {
+            self.position(entity)
+                .expect("Focusable entities must have a GlobalTransform component")
+        };
+        let focused_pos = pos_of(focused);
+        let attraction = |entity: Entity| self.magnets.get(entity).map_or(1.0, |m| m.attraction());
+        let mut candidates: Vec<_> = siblings
+            .iter()
+            .filter(|sibling| {
+                direction.is_in(focused_pos, pos_of(**sibling))
+                    && **sibling != focused
+                    && self.is_visible(**sibling)
+            })
+            .map(|s| (*s, focused_pos.distance(pos_of(*s)) / attraction(*s)))
+            // On equal scores and depths, the last sibling wins.
+            .rev()
+            .collect();
+        candidates.sort_by_key(|(e, score)| (FloatOrd(*score), FloatOrd(-self.depth(*e))));
+        candidates
+    }
+
+    /// The entity in `siblings` that [`NavRequest::Move`] would focus
+    /// from `focused` in each [`Direction`], ignoring wrapping.
+    ///
+    /// Directions without a neighbor are absent from the map.
+    /// See [`FocusNeighbors`] to use the siblings in the menu of `focused`.
+    ///
+    /// [`Direction`]: events::Direction
+    pub fn neighbors(
+        &self,
+        focused: Entity,
+        siblings: &[Entity],
+    ) -> HashMap<events::Direction, Entity> {
+        use events::Direction::*;
+        let all = [
+            North, South, East, West, NorthEast, NorthWest, SouthEast, SouthWest,
+        ];
+        all.into_iter()
+            .filter_map(|dir| Some((dir, *self.resolve_2d(focused, dir, false, siblings)?)))
+            .collect()
+    }
+}
+
+/// System parameter to focus the [`Focusable`] closest to a position,
+/// according to its [`GlobalTransform`].
+///
+/// Useful to preserve the approximate on-screen position of the cursor
+/// when switching from mouse to gamepad input.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::custom::FocusNearest;
+/// fn focus_under_cursor(windows: Query<&Window>, mut focus: FocusNearest) {
+///     let Some(position) = windows.single().cursor_position() else { return; };
+///     focus.focus_nearest_to(position);
+/// }
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[derive(SystemParam)]
+pub struct FocusNearest<'w, 's> {
+    projection: UiProjectionQuery<'w, 's>,
+    focusables: Query<'w, 's, (Entity, &'static Focusable), Without<TreeMenu>>,
+    filter: Option<Res<'w, FocusableFilter>>,
+    requests: EventWriter<'w, NavRequest>,
+}
+/// System parameter to get the neighbors of a [`Focusable`] in its menu,
+/// see [`UiProjectionQuery::neighbors`].
+///
+/// Useful to precompute navigation graphs or to check a layout in tests.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// # use bevy_ui_navigation::custom::FocusNeighbors;
+/// use bevy_ui_navigation::events::Direction;
+///
+/// fn print_neighbors(focused: Query<Entity, With<Focused>>, neighbors: FocusNeighbors) {
+///     for entity in &focused {
+///         let east = neighbors.neighbors(entity).get(&Direction::East).copied();
+///         println!("{entity:?} leads to {east:?} when moving east");
+///     }
+/// }
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[derive(SystemParam)]
+pub struct FocusNeighbors<'w, 's> {
+    projection: UiProjectionQuery<'w, 's>,
+    queries: NavQueries<'w, 's>,
+}
+#[cfg(feature = "bevy_ui")]
+impl<'w, 's> FocusNeighbors<'w, 's> {
+    /// The [`Focusable`] that [`NavRequest::Move`] would focus from `focused`
+    /// in each [`Direction`], among the focusables of the menu of `focused`.
+    ///
+    /// Wrapping and [`NavStrategy`] are ignored. Directions are the requested
+    /// ones, mirrored on screen with [`NavDirectionality::Rtl`].
+    ///
+    /// [`Direction`]: events::Direction
+    pub fn neighbors(&self, focused: Entity) -> HashMap<events::Direction, Entity> {
+        let menu = self.queries.parent_menu(focused).map(|(menu, ..)| menu);
+        let siblings = self.queries.siblings_in(menu);
+        let neighbors = self.projection.neighbors(focused, &siblings);
+        // Mirroring is its own inverse.
+        let requested = |(dir, e)| (self.queries.screen_direction(dir), e);
+        neighbors.into_iter().map(requested).collect()
+    }
+}
+
+/// System parameter to get the order in which navigation visits
+/// the [`Focusable`]s of a menu, without moving the focus.
+///
+/// Useful to check the tab order of a menu in tests.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// # use bevy_ui_navigation::custom::NavOrder;
+/// fn print_tab_order(menus: Query<Entity, With<MenuSetting>>, order: NavOrder) {
+///     for menu in &menus {
+///         println!("{menu:?} tab order: {:?}", order.scope_order(menu));
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct NavOrder<'w, 's> {
+    queries: NavQueries<'w, 's>,
+}
+impl<'w, 's> NavOrder<'w, 's> {
+    /// The non-blocked [`Focusable`]s of `menu`, in the order
+    /// [`NavRequest::ScopeMove`]`(Next)` visits them, from the first one.
+    ///
+    /// This accounts for [`NavDirectionality`] and skips the focusables
+    /// rejected by the [`FocusableFilter`] or [`PointerOnly`].
+    /// When `menu` is [wrapping], `ScopeMove(Next)` goes back
+    /// to the first element after the last one.
+    ///
+    /// [wrapping]: MenuSetting::wrapping
+    pub fn scope_order(&self, menu: Entity) -> Vec<Entity> {
+        self.queries.scope_order(menu)
+    }
+}
+
+#[cfg(feature = "bevy_ui")]
+impl<'w, 's> FocusNearest<'w, 's> {
+    /// Send a [`NavRequest::FocusOn`] to the non-blocked [`Focusable`]
+    /// closest to `position`, returning it.
+    ///
+    /// Does nothing and returns `None` if there is no such [`Focusable`].
+    pub fn focus_nearest_to(&mut self, position: Vec2) -> Option<Entity> {
+        let passes_filter = |e| self.filter.as_ref().map_or(true, |f| f.0.is_focusable(e));
+        let candidates: Vec<_> = self
+            .focusables
+            .iter()
+            .filter(|(e, focus)| !focus.is_blocked() && passes_filter(*e))
+            .filter_map(|(e, _)| self.projection.is_visible(e).then_some(e))
+            .collect();
+        let nearest = *self.projection.nearest_to(position, &candidates)?;
+        self.requests.send(NavRequest::FocusOn(nearest));
+        Some(nearest)
+    }
 }
 
 /// Collection of queries to manage the navigation tree.
@@ -148,8 +628,100 @@ pub(crate) struct NavQueries<'w, 's> {
     parents: Query<'w, 's, &'static Parent>,
     focusables: Query<'w, 's, (Entity, &'static Focusable), Without<TreeMenu>>,
     menus: Query<'w, 's, (Entity, &'static TreeMenu, &'static MenuSetting), Without<Focusable>>,
+    toggleables: Query<'w, 's, &'static Toggleable>,
+    selectables: Query<'w, 's, Has<Selected>, With<Selectable>>,
+    strategies: Query<'w, 's, &'static NavStrategy>,
+    action_sources: Query<'w, 's, &'static ActionSources>,
+    links: Query<'w, 's, &'static NavLinks>,
+    action_targets: Query<'w, 's, &'static ActionTarget>,
+    names: Query<'w, 's, &'static Name>,
+    focus_ids: Query<'w, 's, &'static FocusId>,
+    directionality: Option<Res<'w, NavDirectionality>>,
 }
 impl<'w, 's> NavQueries<'w, 's> {
+    /// Whether the UI is right-to-left, see [`NavDirectionality`].
+    fn is_rtl(&self) -> bool {
+        self.directionality.as_deref() == Some(&NavDirectionality::Rtl)
+    }
+    /// The on-screen direction of a requested `direction`,
+    /// mirrored horizontally if the UI is right-to-left.
+    pub(crate) fn screen_direction(&self, direction: events::Direction) -> events::Direction {
+        if self.is_rtl() {
+            direction.mirrored()
+        } else {
+            direction
+        }
+    }
+    /// The focusables navigable with [`NavRequest::Move`] in `menu`,
+    /// or the non-blocked focusables without menus if `None`.
+    ///
+    /// Focusables outside of any menu form an implicit root menu:
+    /// they can only move to each other, even when other menus exist.
+    pub(crate) fn siblings_in(&self, menu: Option<Entity>) -> Vec<Entity> {
+        let unblocked = |(e, focus): (_, &Focusable)| {
+            (!focus.is_blocked() && self.children.is_navigable(e)).then(|| e)
+        };
+        let loose = |e: &Entity| self.menus.is_empty() || self.parent_menu(*e).is_none();
+        match menu {
+            Some(menu) => self.children.focusables_of(menu),
+            None => self
+                .focusables
+                .iter()
+                .filter_map(unblocked)
+                .filter(loose)
+                .collect(),
+        }
+    }
+    /// Whether `a` and `b` are in the same menu, or both outside of any menu.
+    fn same_menu(&self, a: Entity, b: Entity) -> bool {
+        let menu_of = |e| self.parent_menu(e).map(|(menu, ..)| menu);
+        menu_of(a) == menu_of(b)
+    }
+    /// The focusables of `menu` in [`NavRequest::ScopeMove`] order.
+    pub(crate) fn scope_order(&self, menu: Entity) -> Vec<Entity> {
+        let mut order = self.children.focusables_of(menu);
+        if self.is_rtl() {
+            order.reverse();
+        }
+        order
+    }
+    /// Move in `direction` within `menu`, using the [`NavLinks`] of `focused`
+    /// or the [`NavStrategy`] of `menu`.
+    fn resolve_2d_in<'a>(
+        &self,
+        strategy: &impl MenuNavigationStrategy,
+        menu: Option<(Entity, &TreeMenu, &MenuSetting)>,
+        focused: Entity,
+        direction: events::Direction,
+        cycles: bool,
+        siblings: &'a [Entity],
+    ) -> Option<&'a Entity> {
+        if let Some(target) = self.links.get(focused).ok().and_then(|l| l.get(direction)) {
+            let linked = siblings.iter().find(|e| **e == target);
+            if linked.is_some() {
+                return linked;
+            }
+            warn!(
+                "The {direction:?} NavLinks of {focused:?} is {target:?}, which is blocked \
+                or not a Focusable in the same menu, ignoring it."
+            );
+        }
+        let menu_strategy = menu.and_then(|(entity, ..)| self.strategies.get(entity).ok());
+        match (menu_strategy, menu) {
+            (Some(NavStrategy::Sequential), _) => {
+                let direction = sequential_direction(direction)?;
+                resolve_scope(&focused, direction, cycles, siblings)
+            }
+            (_, Some((_, tree, setting))) if cycles && setting.wrap_to_dormant => {
+                resolve_2d_dormant(strategy, focused, direction, tree, siblings)
+            }
+            (_, Some((_, _, setting))) if cycles && setting.snake_wrap => {
+                resolve_2d_snake(strategy, focused, direction, siblings)
+            }
+            _ => strategy.resolve_2d(focused, direction, cycles, siblings),
+        }
+    }
+
     fn active_menu(
         &self,
         mut entity: Entity,
@@ -176,8 +748,37 @@ impl<'w, 's> NavQueries<'w, 's> {
         }
     }
 
+    /// Whether moving from `focused` in the requested `direction` would wrap
+    /// in a [`MenuSetting::no_wrap_first_move`] menu.
+    fn wraps_first_move(
+        &self,
+        strategy: &impl MenuNavigationStrategy,
+        focused: Entity,
+        direction: events::Direction,
+    ) -> bool {
+        let direction = self.screen_direction(direction);
+        let Some((menu, tree, setting)) = self.parent_menu(focused) else {
+            return false;
+        };
+        if !setting.no_wrap_first_move || !setting.is_wrapping() || !setting.is_2d() {
+            return false;
+        }
+        let siblings = self.children.focusables_of(menu);
+        let menu = Some((menu, &tree, &setting));
+        let to = self.resolve_2d_in(strategy, menu, focused, direction, false, &siblings);
+        to.is_none()
+    }
+
     /// The [`TreeMenu`] containing `focusable`, if any.
     pub(crate) fn parent_menu(&self, focusable: Entity) -> Option<(Entity, TreeMenu, MenuSetting)> {
+        if let Some(menu) = self.children.member_of(focusable) {
+            if let Ok((_, tree, setting)) = self.menus.get(menu) {
+                // Do not create a cycle with the menu `focusable` leads to.
+                if tree.focus_parent != Some(focusable) {
+                    return Some((menu, tree.clone(), *setting));
+                }
+            }
+        }
         let parent = self.parents.get(focusable).ok()?.get();
         match self.menus.get(parent) {
             Ok((_, tree, setting)) => Some((parent, tree.clone(), *setting)),
@@ -187,9 +788,13 @@ impl<'w, 's> NavQueries<'w, 's> {
 
     // TODO: worst case this iterates 3 times through list of focusables and once menus.
     // Could be improved to a single pass.
-    fn pick_first_focused(&self) -> Option<Entity> {
+    fn pick_first_focused(&self, strategy: &impl MenuNavigationStrategy) -> Option<Entity> {
         use FocusState::{Blocked, Focused, Inert};
-        let iter_focused = || self.focusables.iter().filter(|f| f.1.state() != Blocked);
+        let navigable = |entity| self.children.is_navigable(entity);
+        let iter_focused = || {
+            let focusables = self.focusables.iter();
+            focusables.filter(|f| f.1.state() != Blocked && navigable(f.0))
+        };
         let root_menu = || {
             self.menus
                 .iter()
@@ -197,9 +802,9 @@ impl<'w, 's> NavQueries<'w, 's> {
         };
         let any_in_menu = |entity, active_child| {
             match self.focusables.get(active_child) {
-                Ok((entity, _)) => Some(entity),
+                Ok((entity, focus)) if focus.state != Blocked && navigable(entity) => Some(entity),
                 // TODO: non-Inert non-active_child
-                Err(_) => self.children.focusables_of(entity).first().copied(),
+                _ => self.children.focusables_of(entity).first().copied(),
             }
         };
         let any_in_active = || {
@@ -214,7 +819,18 @@ impl<'w, 's> NavQueries<'w, 's> {
         };
         let any_prioritized =
             || iter_focused().find_map(|(e, focus)| (focus.state != Inert).then(|| e));
-        let fallback = || iter_focused().next().map(|(fo, _)| fo);
+        // Focusables outside of menus form the implicit root menu,
+        // so they are preferred over focusables in submenus.
+        let fallback = || {
+            let loose = self.siblings_in(None);
+            let candidates = if loose.is_empty() {
+                iter_focused().map(|(fo, _)| fo).collect()
+            } else {
+                loose
+            };
+            let preferred = strategy.fallback_focus(&candidates);
+            preferred.or_else(|| candidates.first()).copied()
+        };
         let focused = iter_focused().find_map(|(fo, focus)| (focus.state == Focused).then(|| fo));
 
         focused
@@ -224,6 +840,117 @@ impl<'w, 's> NavQueries<'w, 's> {
             .or_else(fallback)
     }
 
+    /// The non-blocked focusable with `id`, if any.
+    fn with_focus_id(&self, id: FocusId) -> Option<Entity> {
+        let has_id = |e: &Entity| self.focus_ids.get(*e) == Ok(&id);
+        let unblocked = self.focusables.iter().filter(|(_, f)| !f.is_blocked());
+        let mut unblocked = unblocked.map(|(e, _)| e);
+        unblocked.find(|e| has_id(e) && self.children.is_navigable(*e))
+    }
+
+    /// Whether `focused` should be immediately activated when entered,
+    /// see [`MenuSetting::auto_activate_single`].
+    fn auto_activates(&self, focused: Entity) -> bool {
+        match self.parent_menu(focused) {
+            Some((menu, _, setting)) if setting.auto_activate_single => {
+                self.children.focusables_of(menu).len() == 1
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `focused` cancels after being activated,
+    /// see [`FocusAction::ActionAndClose`].
+    fn closes_on_action(&self, focused: Entity) -> bool {
+        let action = self.focusables.get(focused).map(|(_, f)| f.action);
+        let can_cancel = |(_, menu, setting): (_, TreeMenu, MenuSetting)| {
+            menu.focus_parent.is_some() || setting.cancel_to.is_some()
+        };
+        action == Ok(FocusAction::ActionAndClose)
+            && self.parent_menu(focused).is_some_and(can_cancel)
+    }
+
+    /// Whether `entity` is in `menu` or one of its submenus.
+    fn is_within(&self, entity: Entity, menu: Entity) -> bool {
+        let in_menu = |e: &Entity| self.parent_menu(*e).is_some_and(|(m, ..)| m == menu);
+        self.root_path(entity).iter().any(in_menu)
+    }
+
+    /// The focusable to focus when entering `menu`, `None` if all
+    /// focusables in `menu` are blocked.
+    fn entry_of(&self, menu_entity: Entity, menu: &TreeMenu) -> Option<Entity> {
+        let is_blocked = |(_, focus): (_, &Focusable)| focus.state == FocusState::Blocked;
+        let active = self.focusables.get(menu.active_child);
+        let active_is_available =
+            !active.map_or(true, is_blocked) && self.children.is_navigable(menu.active_child);
+        if active_is_available {
+            Some(menu.active_child)
+        } else {
+            self.children.focusables_of(menu_entity).first().copied()
+        }
+    }
+
+    /// The [`TreeMenu::active_child`] `menu` would have if built now:
+    /// its first prioritized [`Focusable`], or its first `Focusable`.
+    pub(crate) fn pick_active_child(&self, menu: Entity) -> Option<Entity> {
+        use FocusState::{Active, Focused, Prioritized};
+        let children = self.children.focusables_of(menu);
+        let is_prioritized = |e: &&Entity| {
+            let state = self.focusables.get(**e).map(|(_, f)| f.state);
+            matches!(state, Ok(Prioritized | Active | Focused))
+        };
+        let prioritized = children.iter().find(is_prioritized);
+        prioritized.or_else(|| children.first()).copied()
+    }
+
+    /// The menus of `left` with [`MenuSetting::forget_on_leave`] not on
+    /// the `to` path, with the focusable to reset their active child to.
+    fn forgetful_menus(&self, left: &[Entity], to: &[Entity]) -> Vec<(Entity, Entity)> {
+        let menu_of = |e: &Entity| self.parent_menu(*e).map(|(menu, ..)| menu);
+        let entered: Vec<_> = to.iter().filter_map(menu_of).collect();
+        let forgetful = |e: &Entity| {
+            let (menu, _, setting) = self.parent_menu(*e)?;
+            let entry = *self.children.focusables_of(menu).first()?;
+            let forgets = setting.forget_on_leave && !entered.contains(&menu);
+            forgets.then_some((menu, entry))
+        };
+        left.iter().filter_map(forgetful).collect()
+    }
+
+    /// The menu of `focused` if it has [`MenuSetting::engage_required`],
+    /// with whether it is engaged.
+    fn engage_state(&self, focused: Entity) -> Option<(Entity, bool)> {
+        let (menu, tree, setting) = self.parent_menu(focused)?;
+        setting.engage_required.then_some((menu, tree.engaged))
+    }
+
+    /// The engaged menus of `from` not on the `to` path.
+    fn disengaged_menus(&self, from: &[Entity], to: &[Entity]) -> Vec<Entity> {
+        let menu_of = |e: &Entity| self.parent_menu(*e).map(|(menu, ..)| menu);
+        let entered: Vec<_> = to.iter().filter_map(menu_of).collect();
+        let disengaged = |e: &Entity| {
+            let (menu, tree, _) = self.parent_menu(*e)?;
+            (tree.engaged && !entered.contains(&menu)).then_some(menu)
+        };
+        from.iter().filter_map(disengaged).collect()
+    }
+
+    /// The [`root_path`] of `target` if it is a valid
+    /// [`MenuSetting::cancel_to`] when cancelling from `focused`.
+    ///
+    /// [`root_path`]: Self::root_path
+    fn cancel_target_path(&self, focused: Entity, target: Entity) -> Option<NonEmpty<Entity>> {
+        let (_, focus) = self.focusables.get(target).ok()?;
+        if focus.state() == FocusState::Blocked || !self.children.passes_filter(target) {
+            return None;
+        }
+        // `target` must not be within the menu we are leaving.
+        let (leaving, _, _) = self.parent_menu(focused)?;
+        let to = self.root_path(target);
+        let is_within = |e: &Entity| matches!(self.parent_menu(*e), Some((m, ..)) if m == leaving);
+        (!to.iter().any(is_within)).then_some(to)
+    }
+
     fn root_path(&self, mut from: Entity) -> NonEmpty<Entity> {
         let mut ret = NonEmpty::new(from);
         loop {
@@ -249,27 +976,64 @@ pub(crate) struct MutQueries<'w, 's> {
     parents: Query<'w, 's, &'static Parent>,
     focusables: Query<'w, 's, &'static mut Focusable, Without<TreeMenu>>,
     menus: Query<'w, 's, &'static mut TreeMenu, Without<Focusable>>,
+    members: Query<'w, 's, &'static MenuMember>,
+    toggleables: Query<'w, 's, &'static mut Toggleable>,
 }
 impl<'w, 's> MutQueries<'w, 's> {
     /// Set the [`active_child`](TreeMenu::active_child) field of the enclosing
     /// [`TreeMenu`] and disables the previous one.
     fn set_active_child(&mut self, child: Entity) {
+        let Some(menu) = self.menu_of(child) else {
+            return;
+        };
+        // unwrap: `menu_of` only returns entities with a `TreeMenu`
+        let mut nav_menu = self.menus.get_mut(menu).unwrap();
+        let entity = nav_menu.active_child;
+        if entity != child {
+            nav_menu.dormant.retain(|e| *e != child);
+            nav_menu.dormant.insert(0, entity);
+            nav_menu.dormant.truncate(DORMANT_MEMORY);
+        }
+        nav_menu.active_child = child;
+        // Keep `entity` blocked if `update_focus` just blocked it.
+        let blocked =
+            matches!(self.focusables.get(entity), Ok(f) if f.state == FocusState::Blocked);
+        if !blocked {
+            self.set_entity_focus(entity, FocusState::Inert);
+        }
+    }
+
+    /// The [`TreeMenu`] entity containing `child`, honoring [`MenuMember`].
+    fn menu_of(&self, child: Entity) -> Option<Entity> {
+        // Do not create a cycle with the menu `child` leads to.
+        let is_valid =
+            |menu: &Entity| matches!(self.menus.get(*menu), Ok(m) if m.focus_parent != Some(child));
+        let member_of = self.members.get(child).ok().map(|m| m.0);
+        if let Some(menu) = member_of.filter(is_valid) {
+            return Some(menu);
+        }
         let mut focusable = child;
-        let mut nav_menu = loop {
+        loop {
             // Find the enclosing parent menu.
-            if let Ok(parent) = self.parents.get(focusable) {
-                let parent = parent.get();
-                focusable = parent;
-                if let Ok(menu) = self.menus.get_mut(parent) {
-                    break menu;
-                }
-            } else {
-                return;
+            focusable = self.parents.get(focusable).ok()?.get();
+            if self.menus.contains(focusable) {
+                return Some(focusable);
             }
+        }
+    }
+
+    fn set_selected(&mut self, entity: Entity, selected: bool) {
+        let mut entity = self.commands.entity(entity);
+        match selected {
+            true => entity.insert(Selected),
+            false => entity.remove::<Selected>(),
         };
-        let entity = nav_menu.active_child;
-        nav_menu.active_child = child;
-        self.set_entity_focus(entity, FocusState::Inert);
+    }
+
+    fn set_toggle(&mut self, entity: Entity, value: bool) {
+        if let Ok(mut toggleable) = self.toggleables.get_mut(entity) {
+            toggleable.0 = value;
+        }
     }
 
     fn set_entity_focus(&mut self, entity: Entity, state: FocusState) {
@@ -279,7 +1043,86 @@ impl<'w, 's> MutQueries<'w, 's> {
         }
     }
 
+    /// See [`MenuSetting::engage_required`].
+    fn set_engaged(&mut self, menu: Entity, engaged: bool) {
+        if let Ok(mut nav_menu) = self.menus.get_mut(menu) {
+            nav_menu.engaged = engaged;
+        }
+    }
+
+    /// See [`MenuSetting::no_wrap_first_move`].
+    fn set_moved_since_focus(&mut self, menu: Entity, moved: bool) {
+        if let Ok(mut nav_menu) = self.menus.get_mut(menu) {
+            if nav_menu.moved_since_focus != moved {
+                nav_menu.moved_since_focus = moved;
+            }
+        }
+    }
+
+    /// Reset the active child of `menu` to `entry`, see [`MenuSetting::forget_on_leave`].
+    fn forget(&mut self, menu: Entity, entry: Entity) {
+        let Ok(mut nav_menu) = self.menus.get_mut(menu) else {
+            return;
+        };
+        let forgotten = std::mem::replace(&mut nav_menu.active_child, entry);
+        nav_menu.dormant.clear();
+        if forgotten == entry {
+            return;
+        }
+        // Keep `forgotten` blocked if `update_focus` just blocked it.
+        let prioritized = matches!(
+            self.focusables.get(forgotten),
+            Ok(f) if f.state == FocusState::Prioritized
+        );
+        if prioritized {
+            self.set_entity_focus(forgotten, FocusState::Inert);
+        }
+        self.set_entity_focus(entry, FocusState::Prioritized);
+    }
+
+    /// Like [`Self::set_entity_focus`] for an `entity` leaving the focus path,
+    /// blocking it instead if [`Focusable::block`] was called while it was on it.
+    fn set_entity_unfocused(&mut self, entity: Entity, state: FocusState) {
+        let block_pending = self
+            .focusables
+            .get_mut(entity)
+            .ok()
+            .map(|mut focusable| std::mem::take(&mut focusable.block_pending));
+        let state = if block_pending == Some(true) {
+            FocusState::Blocked
+        } else {
+            state
+        };
+        self.set_entity_focus(entity, state);
+    }
+
+    /// Focus `to_peek` without changing the active trail,
+    /// see [`NavRequest::PeekFocus`].
+    fn peek_focus(&mut self, focused: Entity, to_peek: Entity) -> Peek {
+        let peeked_state = self
+            .focusables
+            .get(to_peek)
+            .map_or(FocusState::Inert, |f| f.state);
+        self.set_entity_focus(focused, FocusState::Prioritized);
+        self.set_entity_focus(to_peek, FocusState::Focused);
+        Peek {
+            peeked: to_peek,
+            peeked_state,
+            previous: focused,
+        }
+    }
+
+    /// Restore the focus state from before [`MutQueries::peek_focus`].
+    fn end_peek(&mut self, peek: Peek) {
+        self.set_entity_focus(peek.peeked, peek.peeked_state);
+        self.set_entity_focus(peek.previous, FocusState::Focused);
+    }
+
     /// Change focus state of relevant entities.
+    ///
+    /// All of `from` but its last entity become [`FocusState::Prioritized`],
+    /// while keeping their menu's `active_child`. So re-entering any menu
+    /// left on the way, however deep, focuses the same entity again.
     fn update_focus(&mut self, from: &[Entity], to: &NonEmpty<Entity>) -> Entity {
         use FocusState as Fs;
 
@@ -290,10 +1133,10 @@ impl<'w, 's> MutQueries<'w, 's> {
             .split_last()
             .map_or((None, from), |(tail, heads)| (Some(tail), heads));
         if let Some(disable) = disable {
-            self.set_entity_focus(*disable, Fs::Inert);
+            self.set_entity_unfocused(*disable, Fs::Inert);
         }
         for &entity in put_to_sleep {
-            self.set_entity_focus(entity, Fs::Prioritized);
+            self.set_entity_unfocused(entity, Fs::Prioritized);
         }
         let (&focus, activate) = to.split_first();
         self.set_active_child(focus);
@@ -364,12 +1207,14 @@ pub enum LockReason {
 /// The navigation system's lock.
 ///
 /// When locked, the navigation system doesn't process any [`NavRequest`].
-/// It only waits on a [`NavRequest::Unlock`] event. It will then continue
-/// processing new requests.
+/// It only waits on a [`NavRequest::Unlock`] event, or the
+/// [`NavLock::unlock_request`] of a [`NavRequest::LockUntil`].
+/// It will then continue processing new requests.
 #[derive(Resource, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
 pub struct NavLock {
     lock_reason: Option<LockReason>,
+    unlock_with: Option<UnlockWith>,
 }
 impl FromWorld for NavLock {
     // PLEASE DO NOT USE THIS.
@@ -381,7 +1226,10 @@ impl FromWorld for NavLock {
 }
 impl NavLock {
     pub(crate) fn new() -> Self {
-        Self { lock_reason: None }
+        Self {
+            lock_reason: None,
+            unlock_with: None,
+        }
     }
     /// The reason why navigation is locked, `None` if currently unlocked.
     pub fn reason(&self) -> Option<LockReason> {
@@ -391,6 +1239,167 @@ impl NavLock {
     pub fn is_locked(&self) -> bool {
         self.lock_reason.is_some()
     }
+    /// The request that unlocks navigation besides [`NavRequest::Unlock`],
+    /// set with [`NavRequest::LockUntil`].
+    pub fn unlock_request(&self) -> Option<UnlockWith> {
+        self.unlock_with
+    }
+    /// Whether `request` unlocks navigation when locked.
+    fn unlocks(&self, request: NavRequest) -> bool {
+        let unlocks_with = |unlock: UnlockWith| unlock.matches(request);
+        request == NavRequest::Unlock || self.unlock_with.is_some_and(unlocks_with)
+    }
+}
+
+/// Emit a [`NavEvent::LockChanged`] when the [`NavLock`] changed.
+pub(crate) fn emit_lock_changes(
+    lock: Res<NavLock>,
+    mut last_reason: Local<Option<LockReason>>,
+    mut events: EventWriter<NavEvent>,
+) {
+    if lock.is_changed() && lock.reason() != *last_reason {
+        *last_reason = lock.reason();
+        events.send(NavEvent::LockChanged(lock.reason()));
+    }
+}
+
+/// System parameter to read which [`Focusable`] each menu focuses
+/// when entered, see [`SetActiveChild`] to change it.
+///
+/// [`SetActiveChild`]: crate::prelude::SetActiveChild
+#[derive(SystemParam)]
+pub struct ActiveChildren<'w, 's> {
+    menus: Query<'w, 's, &'static TreeMenu>,
+}
+impl<'w, 's> ActiveChildren<'w, 's> {
+    /// The [`Focusable`] entered when activating `menu`,
+    /// `None` if `menu` isn't a menu or isn't built yet.
+    pub fn of(&self, menu: Entity) -> Option<Entity> {
+        self.menus.get(menu).ok().map(|menu| menu.active_child)
+    }
+}
+
+/// A stack of menus navigation is confined to, such as overlays.
+///
+/// While the stack isn't empty, focus can't leave the top menu and its
+/// submenus: requests that would focus a [`Focusable`] outside of it emit
+/// a [`NavEvent::NoChanges`]. [`NavScopeStack::push`] focuses the pushed menu,
+/// [`NavScopeStack::pop`] focuses back what was focused before the push.
+///
+/// Add it to your app with `app.init_resource::<NavScopeStack>()`.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// #[derive(Component)]
+/// struct PauseMenu;
+///
+/// fn toggle_pause(
+///     keys: Res<Input<KeyCode>>,
+///     mut stack: ResMut<NavScopeStack>,
+///     pause: Query<Entity, With<PauseMenu>>,
+/// ) {
+///     if !keys.just_pressed(KeyCode::Escape) {
+///         return;
+///     }
+///     if stack.is_empty() {
+///         stack.push(pause.single());
+///     } else {
+///         stack.pop();
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct NavScopeStack {
+    scopes: Vec<Scope>,
+    /// What to focus after a pop, applied next time the stack is updated.
+    restore: Option<Entity>,
+}
+/// A menu in the [`NavScopeStack`].
+#[derive(Debug, Clone, Copy)]
+struct Scope {
+    menu: Entity,
+    /// Whether focus moved into `menu` already.
+    entered: bool,
+    /// What was focused before entering `menu`.
+    restore: Option<Entity>,
+}
+impl NavScopeStack {
+    /// Confine navigation to `menu` and its submenus, focusing `menu`'s
+    /// last focused or prioritized focusable.
+    pub fn push(&mut self, menu: Entity) {
+        let scope = Scope {
+            menu,
+            entered: false,
+            restore: None,
+        };
+        self.scopes.push(scope);
+    }
+    /// Stop confining navigation to the top menu, focusing what was focused
+    /// before it was pushed.
+    ///
+    /// Returns the popped menu, `None` if the stack is empty.
+    pub fn pop(&mut self) -> Option<Entity> {
+        let scope = self.scopes.pop()?;
+        if scope.entered {
+            self.restore = scope.restore;
+        }
+        Some(scope.menu)
+    }
+    /// The menu navigation is currently confined to.
+    pub fn top(&self) -> Option<Entity> {
+        self.scopes.last().map(|scope| scope.menu)
+    }
+    /// How many menus are in the stack.
+    pub fn len(&self) -> usize {
+        self.scopes.len()
+    }
+    /// Whether navigation isn't confined.
+    pub fn is_empty(&self) -> bool {
+        self.scopes.is_empty()
+    }
+}
+
+/// Move focus into menus pushed to the [`NavScopeStack`],
+/// and back when they are popped.
+pub(crate) fn update_scope_stack(
+    mut stack: ResMut<NavScopeStack>,
+    queries: NavQueries,
+    mut requests: EventWriter<NavRequest>,
+) {
+    if !stack.is_changed() {
+        return;
+    }
+    // Do not trigger change detection, so that this runs only on change,
+    // or the next frame when a pushed menu isn't built yet.
+    let inner = stack.bypass_change_detection();
+    let is_focused = |(_, f): &(_, &Focusable)| f.state == FocusState::Focused;
+    let mut focused = queries.focusables.iter().find(is_focused).map(|(e, _)| e);
+    if let Some(restore) = inner.restore.take() {
+        if queries.focusables.contains(restore) {
+            requests.send(NavRequest::FocusOn(restore));
+            focused = Some(restore);
+        }
+    }
+    let mut pending = false;
+    for scope in inner.scopes.iter_mut().filter(|scope| !scope.entered) {
+        let Ok((_, menu, _)) = queries.menus.get(scope.menu) else {
+            pending = true;
+            break;
+        };
+        scope.entered = true;
+        scope.restore = focused;
+        match queries.entry_of(scope.menu, menu) {
+            Some(entry) => {
+                requests.send(NavRequest::FocusOn(entry));
+                focused = Some(entry);
+            }
+            None => warn!("Pushed menu {:?} has no focusable to focus", scope.menu),
+        }
+    }
+    if pending {
+        stack.set_changed();
+    }
 }
 
 /// A menu that isolate children [`Focusable`]s from other focusables
@@ -400,27 +1409,63 @@ impl NavLock {
 /// they will use the [`MenuSetting`] API
 /// and the `TreeMenu` component will be inserted
 /// by the [`insert_tree_menus`] system.
+///
+/// A `TreeMenu` spawned from a scene is kept as is,
+/// even if the entity also has a [`MenuBuilder`].
 #[derive(Debug, Component, Clone)]
-#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, MapEntities)
+)]
 pub(crate) struct TreeMenu {
     /// The [`Focusable`] that sends to this `MenuSetting`
     /// when receiving [`NavRequest::Action`].
     pub(crate) focus_parent: Option<Entity>,
     /// The currently prioritized or active focusable in this menu.
     pub(crate) active_child: Entity,
+    /// Previous `active_child`s, most recent first.
+    pub(crate) dormant: Vec<Entity>,
+    /// Whether [`MenuSetting::engage_required`] menus are engaged.
+    pub(crate) engaged: bool,
+    /// Whether focus moved within this menu since it was established in it,
+    /// see [`MenuSetting::no_wrap_first_move`].
+    pub(crate) moved_since_focus: bool,
+}
+impl TreeMenu {
+    pub(crate) fn new(focus_parent: Option<Entity>, active_child: Entity) -> Self {
+        TreeMenu {
+            focus_parent,
+            active_child,
+            dormant: Vec::new(),
+            engaged: false,
+            moved_since_focus: false,
+        }
+    }
 }
 impl FromWorld for TreeMenu {
     // PLEASE DO NOT USE THIS.
     //
     // This only exists to satisfy `bevy_reflect`'s `ReflectResource` requirement.
     fn from_world(_: &mut bevy::prelude::World) -> Self {
-        TreeMenu {
-            focus_parent: None,
-            active_child: Entity::PLACEHOLDER,
+        TreeMenu::new(None, Entity::PLACEHOLDER)
+    }
+}
+impl MapEntities for TreeMenu {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        if let Some(parent) = &mut self.focus_parent {
+            *parent = entity_mapper.get_or_reserve(*parent);
+        }
+        self.active_child = entity_mapper.get_or_reserve(self.active_child);
+        for dormant in &mut self.dormant {
+            *dormant = entity_mapper.get_or_reserve(*dormant);
         }
     }
 }
 
+/// How many previous [`TreeMenu::active_child`] are remembered.
+pub(crate) const DORMANT_MEMORY: usize = 16;
+
 /// The actions triggered by a [`Focusable`].
 #[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
 #[non_exhaustive]
@@ -449,6 +1494,18 @@ pub enum FocusAction {
     /// you don't want to accidentally unfocus,
     /// or suspending the navigation system while in-game.
     Lock,
+
+    /// If we receive [`NavRequest::Action`]
+    /// while this [`Focusable`] is focused,
+    /// it is activated, then acts as a [`NavRequest::Cancel`].
+    ///
+    /// Both the activation [`NavEvent::NoChanges`] and the
+    /// [`NavEvent::FocusChanged`] of the cancel are emitted,
+    /// in this order. This is useful for "apply and close" buttons.
+    ///
+    /// When there is no menu to cancel to, such as in the root menu,
+    /// this is only activated.
+    ActionAndClose,
 }
 
 /// An [`Entity`] that can be navigated to, using the cursor navigation system.
@@ -462,17 +1519,20 @@ pub enum FocusAction {
 ///
 /// **Note**: You should avoid updating manually the state of [`Focusable`]s.
 /// You should instead use [`NavRequest`] to manipulate and change focus.
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
 pub struct Focusable {
     pub(crate) state: FocusState,
     action: FocusAction,
+    /// Block this once it loses focus, see [`Focusable::block`].
+    block_pending: bool,
 }
 impl Default for Focusable {
     fn default() -> Self {
         Focusable {
             state: FocusState::Inert,
             action: FocusAction::Normal,
+            block_pending: false,
         }
     }
 }
@@ -491,26 +1551,57 @@ impl Focusable {
         self.action
     }
 
-    /// A "cancel" focusable, see [`FocusAction::Cancel`].
-    pub fn cancel() -> Self {
+    /// Whether this `Focusable` is [`FocusState::Focused`].
+    pub fn is_focused(&self) -> bool {
+        self.state == FocusState::Focused
+    }
+    /// Whether this `Focusable` is on the path to the focused element,
+    /// this is `true` for both [`FocusState::Active`] and [`FocusState::Focused`].
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, FocusState::Active | FocusState::Focused)
+    }
+    /// Whether this `Focusable` is [`FocusState::Prioritized`].
+    pub fn is_prioritized(&self) -> bool {
+        self.state == FocusState::Prioritized
+    }
+    /// Whether this `Focusable` is [`FocusState::Blocked`].
+    pub fn is_blocked(&self) -> bool {
+        self.state == FocusState::Blocked
+    }
+    /// Whether this `Focusable` is [`FocusState::Inert`].
+    pub fn is_inert(&self) -> bool {
+        self.state == FocusState::Inert
+    }
+
+    /// A focusable triggering `action` when receiving [`NavRequest::Action`].
+    ///
+    /// Useful when the action comes from data,
+    /// otherwise prefer the named constructors such as [`Focusable::cancel`].
+    pub fn with_action(action: FocusAction) -> Self {
         Focusable {
-            state: FocusState::Inert,
-            action: FocusAction::Cancel,
+            action,
+            ..Self::default()
         }
     }
+    /// A "cancel" focusable, see [`FocusAction::Cancel`].
+    pub fn cancel() -> Self {
+        Self::with_action(FocusAction::Cancel)
+    }
     /// A "lock" focusable, see [`FocusAction::Lock`].
     pub fn lock() -> Self {
-        Focusable {
-            state: FocusState::Inert,
-            action: FocusAction::Lock,
-        }
+        Self::with_action(FocusAction::Lock)
+    }
+    /// An "apply and close" focusable, see [`FocusAction::ActionAndClose`].
+    pub fn action_and_close() -> Self {
+        Self::with_action(FocusAction::ActionAndClose)
     }
     /// A focusable that will get highlighted in priority when none are set yet.
     ///
     /// **WARNING**: Only use this when creating the UI.
     /// Any of the following state is unspecified
     /// and will likely result in broken behavior:
-    /// * Having multiple prioritized `Focusable`s in the same menu.
+    /// * Having multiple prioritized `Focusable`s in the same menu,
+    ///   a warning is logged in debug builds when this happens.
     /// * Updating an already existing `Focusable` with this.
     ///
     /// # Example
@@ -560,38 +1651,42 @@ impl Focusable {
 
     /// Prevent this [`Focusable`] from gaining focus until it is unblocked.
     ///
-    /// **Note**: Due to the way focus is handled, this does nothing
-    /// when the [`Focusable::state`] is [`FocusState::Active`]
-    /// or [`FocusState::Focused`].
+    /// When the [`Focusable::state`] is [`FocusState::Active`]
+    /// or [`FocusState::Focused`], the navigation system first moves focus
+    /// to a sibling in the same menu, emitting a [`NavEvent::FocusChanged`],
+    /// and then blocks it. If there is no such sibling, it is blocked
+    /// once it loses focus.
     ///
-    /// Returns `true` if `self` has succesfully been blocked
-    /// (its [`Focusable::state`] was either `Inert` or `Prioritized`).
+    /// Returns `true` if `self` is or will be blocked
+    /// (its [`Focusable::state`] wasn't already `Blocked`).
     ///
     /// # Limitations
     ///
-    /// - If all the children of a menu are blocked, when activating the menu's
-    ///   parent, the block state of the last active focusable will be ignored.
     /// - When `FocusOn` to a focusable in a menu reachable from a blocked
     ///   focusable, its block state will be ignored.
     pub fn block(&mut self) -> bool {
-        use FocusState::{Blocked, Inert, Prioritized};
-        let blockable = matches!(self.state(), Inert | Prioritized);
-        if blockable {
-            self.state = Blocked;
+        use FocusState::{Active, Blocked, Focused, Inert, Prioritized};
+        match self.state() {
+            Inert | Prioritized => self.state = Blocked,
+            Active | Focused => self.block_pending = true,
+            Blocked => return false,
         }
-        blockable
+        true
     }
 
     /// Allow this [`Focusable`] to gain focus again,
     /// setting it to [`FocusState::Inert`].
     ///
-    /// Returns `true` if `self`'s state was [`FocusState::Blocked`].
+    /// Also cancels a [`Focusable::block`] waiting for focus to move away.
+    ///
+    /// Returns `true` if `self`'s state was [`FocusState::Blocked`]
+    /// or a block was cancelled.
     pub fn unblock(&mut self) -> bool {
         if self.state() == FocusState::Blocked {
             self.state = FocusState::Inert;
             true
         } else {
-            false
+            std::mem::take(&mut self.block_pending)
         }
     }
 }
@@ -620,6 +1715,339 @@ impl Focusable {
 #[non_exhaustive]
 pub struct Focused;
 
+/// A boolean state flipped when its [`Focusable`] receives
+/// [`NavRequest::Action`], such as a checkbox or a toggle button.
+///
+/// Instead of entering a submenu, the [`NavRequest::Action`]
+/// emits a [`NavEvent::Toggled`] with the new value.
+/// Use `Changed<Toggleable>` to update the rendering.
+///
+/// [`FocusAction::Cancel`] and [`FocusAction::Lock`] take precedence
+/// over `Toggleable`.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct Toggleable(pub bool);
+
+/// A [`Focusable`] that can be added to the selection
+/// with [`NavRequest::ToggleSelect`].
+///
+/// This allows selecting several elements, independently from
+/// the focus, for example in an inventory. Selected entities have
+/// the [`Selected`] marker, see also [`Selection`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct Selectable;
+
+/// Marker for selected [`Selectable`]s, toggled by [`NavRequest::ToggleSelect`].
+///
+/// You can add it yourself to select an entity, for example when
+/// restoring a previous selection.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+#[component(storage = "SparseSet")]
+pub struct Selected;
+
+/// System parameter to read the [`Selected`] entities.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// fn drop_selected(mut commands: Commands, selection: Selection) {
+///     for entity in selection.iter() {
+///         commands.entity(entity).despawn_recursive();
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct Selection<'w, 's> {
+    selected: Query<'w, 's, Entity, With<Selected>>,
+}
+impl<'w, 's> Selection<'w, 's> {
+    /// Iterate over all [`Selected`] entities.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.selected.iter()
+    }
+    /// Whether `entity` is [`Selected`].
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.selected.contains(entity)
+    }
+    /// How many entities are [`Selected`].
+    pub fn len(&self) -> usize {
+        self.selected.iter().len()
+    }
+    /// Whether nothing is [`Selected`].
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+}
+
+/// On [`NavRequest::Action`], focus the menu with this [`Name`]
+/// instead of the menu reachable from this [`Focusable`].
+///
+/// The target menu focuses its last focused or prioritized [`Focusable`].
+/// Useful for buttons opening a menu from anywhere in the UI,
+/// such as a "settings" button, without passing [`Entity`]s around.
+///
+/// A [`NavEvent::NoChanges`] is emitted when no menu has this [`Name`].
+///
+/// [`FocusAction::Cancel`] and [`FocusAction::Lock`] take precedence
+/// over `ActionTarget`.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct ActionTarget(pub Name);
+impl ActionTarget {
+    /// Target the menu named `name`.
+    pub fn new(name: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(Name::new(name))
+    }
+}
+
+/// The kinds of input that can activate this [`Focusable`]
+/// with a [`NavRequest::ActionFrom`], all of them by default.
+///
+/// Useful for dangerous buttons that require deliberate input,
+/// such as a "delete save" button only activated by the keyboard.
+///
+/// [`NavRequest::Action`] is not affected, since it has no source.
+///
+/// ```rust
+/// # use bevy_ui_navigation::prelude::*;
+/// let keyboard_or_gamepad = ActionSources::KEYBOARD | ActionSources::GAMEPAD;
+/// assert!(!keyboard_or_gamepad.accepts(InputSource::Pointer));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct ActionSources(u8);
+impl ActionSources {
+    /// Accept [`InputSource::Keyboard`].
+    pub const KEYBOARD: Self = Self(1 << 0);
+    /// Accept [`InputSource::Gamepad`].
+    pub const GAMEPAD: Self = Self(1 << 1);
+    /// Accept [`InputSource::Pointer`].
+    pub const POINTER: Self = Self(1 << 2);
+    /// Accept all sources, the default.
+    pub const ALL: Self = Self(Self::KEYBOARD.0 | Self::GAMEPAD.0 | Self::POINTER.0);
+
+    /// Whether an action from `source` activates the [`Focusable`].
+    pub fn accepts(&self, source: InputSource) -> bool {
+        let flag = match source {
+            InputSource::Keyboard => Self::KEYBOARD,
+            InputSource::Gamepad => Self::GAMEPAD,
+            InputSource::Pointer => Self::POINTER,
+        };
+        self.0 & flag.0 != 0
+    }
+}
+impl Default for ActionSources {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+impl std::ops::BitOr for ActionSources {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// An identifier for a [`Focusable`], stable across runs unlike [`Entity`].
+///
+/// Useful to play a sound or record analytics when a specific
+/// [`Focusable`] is focused, see [`NavEvent::focused_id`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct FocusId(pub u64);
+
+/// A [`Focusable`] that can only be focused with the mouse or touch.
+///
+/// [`NavRequest::Move`], [`NavRequest::ScopeMove`] and the initial focus
+/// skip it, but [`NavRequest::FocusOn`] still focuses it, so clicking it
+/// still activates it. Useful for small buttons, such as a "close" cross
+/// in the corner of a window, that are redundant with [`NavRequest::Cancel`].
+///
+/// This is the opposite of `ClickActivates(false)`.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct PointerOnly;
+
+/// Focus the [`Focusable`] with this [`FocusId`] when nothing is focused,
+/// rather than the [`Focusable::prioritized`] one.
+///
+/// Useful to restore the last focused element of a previous session.
+/// When no non-blocked [`Focusable`] has this id, the focus is chosen as usual.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct InitialFocus(pub FocusId);
+
+/// Temporarily attract [`NavRequest::Move`]s toward this [`Focusable`].
+///
+/// When moving focus with [`UiProjectionQuery`], a magnetized sibling in the
+/// requested direction counts as `1.0 + strength` times closer than it is.
+/// So it wins ties, and beats closer siblings within the direction,
+/// for example to steer the player toward a button during a tutorial.
+///
+/// Unlike [`Focusable::prioritized`], which only decides what to focus
+/// when entering a menu, this affects every move. The component is removed
+/// after `ttl` seconds, if set.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct FocusMagnet {
+    /// How strongly this attracts the focus, negative values count as `0.0`.
+    pub strength: f32,
+    /// Seconds until the magnet is removed, `None` to keep it forever.
+    pub ttl: Option<f32>,
+}
+impl Default for FocusMagnet {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+impl FocusMagnet {
+    /// A magnet of given `strength`, never removed.
+    pub fn new(strength: f32) -> Self {
+        FocusMagnet {
+            strength,
+            ttl: None,
+        }
+    }
+    /// Remove the magnet after `seconds`.
+    pub fn with_ttl(mut self, seconds: f32) -> Self {
+        self.ttl = Some(seconds);
+        self
+    }
+    /// How much closer than its actual distance a magnetized entity is.
+    #[cfg(feature = "bevy_ui")]
+    fn attraction(&self) -> f32 {
+        1.0 + self.strength.max(0.0)
+    }
+}
+
+/// Explicit neighbors of a [`Focusable`], overriding the
+/// [`MenuNavigationStrategy`] for [`NavRequest::Move`].
+///
+/// Moving in a direction with a link focuses the linked entity,
+/// other directions, including diagonals, use the navigation strategy.
+/// Links must point to a non-blocked [`Focusable`] in the same menu,
+/// otherwise they are ignored with a warning.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// fn link(mut commands: Commands, start: Entity, options: Entity) {
+///     commands.entity(start).insert(NavLinks::new().east(options));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, MapEntities)
+)]
+pub struct NavLinks {
+    /// Focused on [`Direction::North`](events::Direction::North) moves.
+    pub north: Option<Entity>,
+    /// Focused on [`Direction::South`](events::Direction::South) moves.
+    pub south: Option<Entity>,
+    /// Focused on [`Direction::East`](events::Direction::East) moves.
+    pub east: Option<Entity>,
+    /// Focused on [`Direction::West`](events::Direction::West) moves.
+    pub west: Option<Entity>,
+}
+impl NavLinks {
+    /// No links, all directions use the navigation strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the [`north`](Self::north) link.
+    pub fn north(mut self, target: Entity) -> Self {
+        self.north = Some(target);
+        self
+    }
+    /// Set the [`south`](Self::south) link.
+    pub fn south(mut self, target: Entity) -> Self {
+        self.south = Some(target);
+        self
+    }
+    /// Set the [`east`](Self::east) link.
+    pub fn east(mut self, target: Entity) -> Self {
+        self.east = Some(target);
+        self
+    }
+    /// Set the [`west`](Self::west) link.
+    pub fn west(mut self, target: Entity) -> Self {
+        self.west = Some(target);
+        self
+    }
+    /// The link in `direction`, if any.
+    pub fn get(&self, direction: events::Direction) -> Option<Entity> {
+        use events::Direction::*;
+        match direction {
+            North => self.north,
+            South => self.south,
+            East => self.east,
+            West => self.west,
+            NorthEast | NorthWest | SouthEast | SouthWest => None,
+        }
+    }
+    /// All the set links.
+    pub fn targets(&self) -> impl Iterator<Item = Entity> {
+        [self.north, self.south, self.east, self.west]
+            .into_iter()
+            .flatten()
+    }
+}
+impl MapEntities for NavLinks {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        let links = [
+            &mut self.north,
+            &mut self.south,
+            &mut self.east,
+            &mut self.west,
+        ];
+        for target in links.into_iter().flatten() {
+            *target = entity_mapper.get_or_reserve(*target);
+        }
+    }
+}
+
+/// Remove [`FocusMagnet`]s once their `ttl` ran out.
+pub(crate) fn expire_focus_magnets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut magnets: Query<(Entity, &mut FocusMagnet)>,
+) {
+    for (entity, mut magnet) in &mut magnets {
+        let Some(ttl) = &mut magnet.ttl else {
+            continue;
+        };
+        *ttl -= time.delta_seconds();
+        if *ttl <= 0.0 {
+            commands.entity(entity).remove::<FocusMagnet>();
+        }
+    }
+}
+
+/// How far apart in pixels can focusables be while still considered
+/// on the same row or column.
+#[cfg(feature = "bevy_ui")]
+const ALIGNMENT_TOLERANCE: f32 = 1.0;
+
+/// Unit vector pointing toward `direction`, `y` pointing downward.
+#[cfg(feature = "bevy_ui")]
+fn direction_vec(direction: events::Direction) -> Vec2 {
+    use events::Direction::*;
+    match direction {
+        North => Vec2::NEG_Y,
+        South => Vec2::Y,
+        East => Vec2::X,
+        West => Vec2::NEG_X,
+        NorthEast => Vec2::new(1.0, -1.0).normalize(),
+        NorthWest => Vec2::new(-1.0, -1.0).normalize(),
+        SouthEast => Vec2::new(1.0, 1.0).normalize(),
+        SouthWest => Vec2::new(-1.0, 1.0).normalize(),
+    }
+}
+
 #[cfg(feature = "bevy_ui")]
 impl<'w, 's> MenuNavigationStrategy for UiProjectionQuery<'w, 's> {
     fn resolve_2d<'a>(
@@ -632,19 +2060,36 @@ impl<'w, 's> MenuNavigationStrategy for UiProjectionQuery<'w, 's> {
         use events::Direction::*;
 
         let pos_of = |entity: Entity| {
-            self.transforms
-                .get(entity)
+            self.position(entity)
                 .expect("Focusable entities must have a GlobalTransform component")
-                .translation()
-                .xy()
         };
-        let focused_pos = pos_of(focused);
-        let closest = siblings
-            .iter()
-            .filter(|sibling| {
-                direction.is_in(focused_pos, pos_of(**sibling)) && **sibling != focused
-            })
-            .max_by_key(|s| FloatOrd(-focused_pos.distance_squared(pos_of(**s))));
+        let focused_pos = pos_of(focused);
+        let candidates = self.candidates_2d(focused, direction, siblings);
+        let closest = candidates
+            .first()
+            .and_then(|(best, _)| siblings.iter().find(|s| *s == best));
+
+        // Single row or column menus wrap to the other end of the menu,
+        // regardless of the ScreenBoundaries.
+        let horizontal = matches!(direction, East | West);
+        let across = |entity: Entity| {
+            if horizontal {
+                pos_of(entity).y
+            } else {
+                pos_of(entity).x
+            }
+        };
+        let is_1d = !direction.is_diagonal()
+            && siblings
+                .iter()
+                .all(|s| (across(*s) - across(focused)).abs() < ALIGNMENT_TOLERANCE);
+        if closest.is_none() && cycles && is_1d {
+            let along = |s: &&Entity| FloatOrd(pos_of(**s).dot(direction_vec(direction)));
+            let others = siblings
+                .iter()
+                .filter(|s| **s != focused && self.is_visible(**s));
+            return others.min_by_key(along);
+        }
         match (closest, self.boundaries.as_ref()) {
             (None, None) if cycles => {
                 warn!(
@@ -660,32 +2105,170 @@ impl<'w, 's> MenuNavigationStrategy for UiProjectionQuery<'w, 's> {
                 let (x, y) = (boundaries.position.x, boundaries.position.y);
                 let edge = boundaries.screen_edge;
                 let scale = boundaries.scale;
-                let focused_pos = match direction {
-                    // NOTE: up/down axises are inverted in bevy
-                    South => Vec2::new(focused_pos.x, y - scale * edge.min.y),
-                    North => Vec2::new(focused_pos.x, y + scale * edge.max.y),
-                    East => Vec2::new(x - edge.min.x * scale, focused_pos.y),
-                    West => Vec2::new(x + edge.max.x * scale, focused_pos.y),
+                let wrapped_x = match direction {
+                    East | NorthEast | SouthEast => x - edge.min.x * scale,
+                    West | NorthWest | SouthWest => x + edge.max.x * scale,
+                    North | South => focused_pos.x,
                 };
+                // NOTE: up/down axises are inverted in bevy
+                let wrapped_y = match direction {
+                    South | SouthEast | SouthWest => y - scale * edge.min.y,
+                    North | NorthEast | NorthWest => y + scale * edge.max.y,
+                    East | West => focused_pos.y,
+                };
+                let focused_pos = Vec2::new(wrapped_x, wrapped_y);
                 siblings
                     .iter()
+                    .filter(|s| self.is_visible(**s))
                     .max_by_key(|s| FloatOrd(-focused_pos.distance_squared(pos_of(**s))))
             }
             (anyelse, _) => anyelse,
         }
     }
+
+    fn wrap_region(
+        &self,
+        wrapped_to: Entity,
+        direction: events::Direction,
+        siblings: &[Entity],
+    ) -> Vec<Entity> {
+        use events::Direction::*;
+
+        let across = |entity: Entity| {
+            let position = self.position(entity)?;
+            match direction {
+                East | West => Some(position.y),
+                North | South => Some(position.x),
+                _ => None,
+            }
+        };
+        let Some(region) = across(wrapped_to) else {
+            return Vec::new();
+        };
+        let in_region =
+            |e: &&Entity| across(**e).is_some_and(|a| (a - region).abs() < ALIGNMENT_TOLERANCE);
+        siblings.iter().filter(in_region).copied().collect()
+    }
+
+    fn fallback_focus<'a>(&self, candidates: &'a [Entity]) -> Option<&'a Entity> {
+        let screen_center = || {
+            let boundaries = self.boundaries.as_ref()?;
+            let edge = boundaries.screen_edge;
+            Some(boundaries.position + boundaries.scale * (edge.min + edge.max) / 2.)
+        };
+        let explicit_point = self.fallback_point.as_ref().and_then(|p| p.0);
+        let point = explicit_point.or_else(screen_center)?;
+        self.nearest_to(point, candidates)
+    }
 }
 
-/// Returns the next or previous entity based on `direction`.
-fn resolve_scope(
-    focused: Entity,
-    direction: events::ScopeDirection,
-    cycles: bool,
-    siblings: &[Entity],
-) -> Option<&Entity> {
-    let focused_index = siblings.iter().position(|e| *e == focused)?;
-    let new_index = resolve_index(focused_index, cycles, direction, siblings.len() - 1);
-    new_index.and_then(|i| siblings.get(i))
+/// A [`MenuNavigationStrategy`] following the CSS grid cells of `bevy_ui` nodes.
+///
+/// [`NavRequest::Move`] goes to the closest [`Focusable`] in the requested
+/// direction according to the `grid_row` and `grid_column` start lines
+/// of their [`Style`], rather than their position on screen.
+/// Useful for grids with cells of different sizes, where the centers of
+/// cells in the same row or column are not aligned.
+///
+/// Menus where `focused` or a sibling has no positive row and column
+/// start lines use [`UiProjectionQuery`] instead.
+///
+/// Use it with `GenericNavigationPlugin::<UiGridQuery>::new()`.
+#[cfg(feature = "bevy_ui")]
+#[derive(SystemParam)]
+pub struct UiGridQuery<'w, 's> {
+    projection: UiProjectionQuery<'w, 's>,
+    styles: Query<'w, 's, &'static Style>,
+}
+#[cfg(feature = "bevy_ui")]
+impl<'w, 's> UiGridQuery<'w, 's> {
+    /// The column and row start lines of `entity`, `None` if not set or negative.
+    fn cell(&self, entity: Entity) -> Option<IVec2> {
+        let style = self.styles.get(entity).ok()?;
+        let line = |placement: bevy::ui::GridPlacement| placement.get_start().filter(|l| *l > 0);
+        let (column, row) = (line(style.grid_column)?, line(style.grid_row)?);
+        Some(IVec2::new(column.into(), row.into()))
+    }
+    /// The cells of all `siblings`, `None` if one of them has no cell.
+    fn cells<'a>(&self, siblings: &'a [Entity]) -> Option<Vec<(&'a Entity, IVec2)>> {
+        siblings.iter().map(|s| Some((s, self.cell(*s)?))).collect()
+    }
+}
+#[cfg(feature = "bevy_ui")]
+impl<'w, 's> MenuNavigationStrategy for UiGridQuery<'w, 's> {
+    fn resolve_2d<'a>(
+        &self,
+        focused: Entity,
+        direction: events::Direction,
+        cycles: bool,
+        siblings: &'a [Entity],
+    ) -> Option<&'a Entity> {
+        use events::Direction::*;
+
+        let (Some(from), Some(cells)) = (self.cell(focused), self.cells(siblings)) else {
+            return self
+                .projection
+                .resolve_2d(focused, direction, cycles, siblings);
+        };
+        let step = match direction {
+            East => IVec2::X,
+            West => IVec2::NEG_X,
+            South => IVec2::Y,
+            North => IVec2::NEG_Y,
+            NorthEast => IVec2::new(1, -1),
+            NorthWest => IVec2::new(-1, -1),
+            SouthEast => IVec2::new(1, 1),
+            SouthWest => IVec2::new(-1, 1),
+        };
+        // Whether `offset` along one axis follows `step` along the same axis.
+        let follows = |offset: i32, step: i32| match step {
+            0 => offset == 0,
+            step => offset.signum() == step,
+        };
+        let others = cells.iter().filter(|(e, _)| **e != focused);
+        let offsets = others.map(|(e, cell)| (*e, *cell - from));
+        let in_line = offsets.filter(|(_, d)| {
+            let across = |d: i32, step: i32| step != 0 || d == 0;
+            across(d.x, step.x) && across(d.y, step.y)
+        });
+        let in_line: Vec<_> = in_line.collect();
+        let in_direction = in_line
+            .iter()
+            .filter(|(_, d)| follows(d.x, step.x) && follows(d.y, step.y));
+        let closest = in_direction.min_by_key(|(_, d)| d.x.abs() + d.y.abs());
+        match closest {
+            Some((closest, _)) => Some(closest),
+            None if cycles && !direction.is_diagonal() => {
+                let farthest = in_line.iter().min_by_key(|(_, d)| d.dot(step));
+                farthest.map(|(e, _)| *e)
+            }
+            None => None,
+        }
+    }
+
+    fn wrap_region(
+        &self,
+        wrapped_to: Entity,
+        direction: events::Direction,
+        siblings: &[Entity],
+    ) -> Vec<Entity> {
+        use events::Direction::*;
+
+        let (Some(wrapped), Some(cells)) = (self.cell(wrapped_to), self.cells(siblings)) else {
+            return self.projection.wrap_region(wrapped_to, direction, siblings);
+        };
+        let in_region = |cell: IVec2| match direction {
+            East | West => cell.y == wrapped.y,
+            North | South => cell.x == wrapped.x,
+            _ => false,
+        };
+        let region = cells.into_iter().filter(|(_, cell)| in_region(*cell));
+        region.map(|(e, _)| *e).collect()
+    }
+
+    fn fallback_focus<'a>(&self, candidates: &'a [Entity]) -> Option<&'a Entity> {
+        self.projection.fallback_focus(candidates)
+    }
 }
 
 /// Find the event created by `request` where the focused element is `focused`.
@@ -723,34 +2306,123 @@ fn resolve<STGY: MenuNavigationStrategy>(
         };
     }
     match request {
-        Lock => {
+        Lock | LockUntil(_) => {
             if lock.is_locked() {
                 return NavEvent::NoChanges { from, request };
             }
             let reason = LockReason::NavRequest;
             lock.lock_reason = Some(reason);
+            if let LockUntil(unlock_with) = request {
+                lock.unlock_with = Some(unlock_with);
+            }
             NavEvent::Locked(reason)
         }
-        Move(direction) => {
-            let (parent, cycles) = match queries.parent_menu(focused) {
+        Move(requested) => {
+            let direction = queries.screen_direction(requested);
+            let menu = queries.parent_menu(focused);
+            let (parent, cycles) = match &menu {
                 Some(val) if !val.2.is_2d() => return NavEvent::NoChanges { from, request },
-                Some(val) => (Some(val.0), !val.2.bound()),
+                Some(val) => (Some(val.0), !val.2.is_bound()),
                 None => (None, true),
             };
-            let unblocked = |(e, focus): (_, &Focusable)| (focus.state != Blocked).then(|| e);
-            let siblings = match parent {
-                Some(parent) => queries.children.focusables_of(parent),
-                None => queries.focusables.iter().filter_map(unblocked).collect(),
-            };
-            let to = strategy.resolve_2d(focused, direction, cycles, &siblings);
+            let siblings = queries.siblings_in(parent);
+            let menu = menu.as_ref().map(|(e, tree, setting)| (*e, tree, setting));
+            let to = queries.resolve_2d_in(strategy, menu, focused, direction, cycles, &siblings);
+            let overflows = menu.is_some_and(|(.., setting)| setting.move_overflow_scopes);
+            // `ScopeMove` is never promoted back to `Move`, so this can't loop.
+            // It handles `NavDirectionality` itself, hence `requested`.
+            if let (None, true, Some(scope_dir)) = (to, overflows, sequential_direction(requested))
+            {
+                let mut from = from.to_vec();
+                from.truncate(from.len() - 1);
+                let event = resolve(focused, ScopeMove(scope_dir), queries, lock, from, strategy);
+                if !matches!(event, NavEvent::NoChanges { .. }) {
+                    return event;
+                }
+            }
             NavEvent::focus_changed(*or_none!(to), from)
         }
+        MoveBy(delta) => {
+            use events::Direction::{East, North, South, West};
+            let steps = |positive, negative, delta: i32| {
+                let direction = if delta >= 0 { positive } else { negative };
+                (0..delta.unsigned_abs()).map(move |_| direction)
+            };
+            let steps = steps(East, West, delta.x).chain(steps(South, North, delta.y));
+            let mut to = focused;
+            for direction in steps {
+                match resolve(to, Move(direction), queries, lock, Vec::new(), strategy) {
+                    NavEvent::FocusChanged { to: next, .. } => to = *next.first(),
+                    _ => break,
+                }
+            }
+            if to == focused {
+                return NavEvent::NoChanges { from, request };
+            }
+            // Steps may overflow into other menus, see `MenuSetting::move_overflow_scopes`.
+            let mut from = queries.root_path(focused);
+            let mut to = queries.root_path(to);
+            trim_common_tail(&mut from, &mut to);
+            NavEvent::path_changed(to, from)
+        }
         Cancel => {
-            let to = or_none!(queries.parent_menu(focused));
-            let to = or_none!(to.1.focus_parent);
+            let (_, menu, setting) = or_none!(queries.parent_menu(focused));
+            if let Some(target) = setting.cancel_to {
+                if let Some(mut to) = queries.cancel_target_path(focused, target) {
+                    let mut from = queries.root_path(focused);
+                    trim_common_tail(&mut from, &mut to);
+                    return NavEvent::path_changed(to, from);
+                }
+                warn!("MenuSetting::cancel_to target {target:?} is not a valid target, ignoring");
+            }
+            let to = or_none!(menu.focus_parent);
             from.push(to);
             NavEvent::focus_changed(to, from)
         }
+        CancelToRoot => {
+            let from = queries.root_path(focused);
+            if from.len().get() == 1 {
+                return NavEvent::NoChanges { from, request };
+            }
+            NavEvent::focus_changed(*from.last(), from)
+        }
+        NextRoot | PrevRoot => {
+            let root = *queries.root_path(focused).last();
+            let (current, ..) = or_none!(queries.parent_menu(root));
+            let mut roots: Vec<_> = queries
+                .menus
+                .iter()
+                .filter(|(_, menu, _)| menu.focus_parent.is_none())
+                .collect();
+            roots.sort_by_key(|(entity, ..)| entity.index());
+            let index = or_none!(roots.iter().position(|(e, ..)| *e == current));
+            let (before, after) = roots.split_at(index);
+            let after = &after[1..];
+            let entry = |(e, menu, _): &(Entity, &TreeMenu, _)| queries.entry_of(*e, menu);
+            let to = if request == NextRoot {
+                after.iter().chain(before).find_map(entry)
+            } else {
+                before
+                    .iter()
+                    .rev()
+                    .chain(after.iter().rev())
+                    .find_map(entry)
+            };
+            NavEvent::path_changed(NonEmpty::new(or_none!(to)), queries.root_path(focused))
+        }
+        Refocus => {
+            let path = queries.root_path(focused);
+            NavEvent::path_changed(path.clone(), path)
+        }
+        ActionFrom(source) => {
+            let sources = queries.action_sources.get(focused);
+            if !sources.map_or(true, |s| s.accepts(source)) {
+                return NavEvent::NoChanges { from, request };
+            }
+            let mut from = from.to_vec();
+            from.truncate(from.len() - 1);
+            resolve(focused, Action, queries, lock, from, strategy)
+        }
         Action => {
             match queries.focusables.get(focused).map(|e| e.1.action) {
                 Ok(FocusAction::Cancel) => {
@@ -763,29 +2435,87 @@ fn resolve<STGY: MenuNavigationStrategy>(
                     lock.lock_reason = Some(reason);
                     return NavEvent::Locked(reason);
                 }
+                // Only activated here, `listen_nav_requests` handles the close.
+                Ok(FocusAction::ActionAndClose) => return NavEvent::NoChanges { from, request },
                 Err(_) | Ok(FocusAction::Normal) => {}
             }
+            if let Ok(ActionTarget(name)) = queries.action_targets.get(focused) {
+                let is_named = |(e, ..): &(Entity, _, _)| queries.names.get(*e) == Ok(name);
+                let (menu_entity, menu, _) = or_none!(queries.menus.iter().find(is_named));
+                let entry = or_none!(queries.entry_of(menu_entity, menu));
+                let mut from = queries.root_path(focused);
+                let mut to = queries.root_path(entry);
+                trim_common_tail(&mut from, &mut to);
+                return if from == to {
+                    NavEvent::NoChanges { from, request }
+                } else {
+                    NavEvent::path_changed(to, from)
+                };
+            }
+            if let Ok(toggleable) = queries.toggleables.get(focused) {
+                let value = !toggleable.0;
+                return NavEvent::Toggled {
+                    entity: focused,
+                    value,
+                };
+            }
             let child_menu = child_menu(focused, queries);
-            let (_, menu, _) = or_none!(child_menu);
-            let to = (menu.active_child, from.clone().into()).into();
-            NavEvent::FocusChanged { to, from }
+            let (menu_entity, menu, _) = or_none!(child_menu);
+            // Do not enter menus where every focusable is blocked.
+            let entry = or_none!(queries.entry_of(menu_entity, menu));
+            let to = (entry, from.clone().into()).into();
+            NavEvent::path_changed(to, from)
         }
+        SecondaryAction => NavEvent::SecondaryActivated(focused),
+        TertiaryAction => NavEvent::TertiaryActivated(focused),
+        ToggleSelect => match queries.selectables.get(focused) {
+            Ok(selected) => NavEvent::SelectionChanged {
+                entity: focused,
+                selected: !selected,
+            },
+            Err(_) => NavEvent::NoChanges { from, request },
+        },
         // "Tab move" nested movement
-        ScopeMove(scope_dir) => {
+        ScopeMove(_) | ScopeMove2d(_) => {
             let (parent, menu, setting) = or_none!(queries.parent_menu(focused));
             let siblings = queries.children.focusables_of(parent);
             if !setting.is_scope() {
                 let focused = or_none!(menu.focus_parent);
                 resolve(focused, request, queries, lock, from.into(), strategy)
             } else {
-                let cycles = !setting.bound();
-                let to = or_none!(resolve_scope(focused, scope_dir, cycles, &siblings));
+                let cycles = !setting.is_bound();
+                let rtl = queries.is_rtl();
+                let to = match request {
+                    ScopeMove(scope_dir) => {
+                        let scope_dir = if rtl { scope_dir.reverse() } else { scope_dir };
+                        resolve_scope(&focused, scope_dir, cycles, &siblings)
+                    }
+                    ScopeMove2d(dir) => {
+                        let dir = queries.screen_direction(dir);
+                        let menu = Some((parent, &menu, &setting));
+                        queries.resolve_2d_in(strategy, menu, focused, dir, cycles, &siblings)
+                    }
+                    _ => unreachable!("Only scope requests are handled here"),
+                };
+                if let (None, ScopeMove(direction)) = (to, request) {
+                    // `resolve_scope` is also `None` when `focused` isn't in `siblings`,
+                    // or when cycling with no other sibling.
+                    if siblings.contains(&focused) && !cycles {
+                        return NavEvent::ScopeEdge {
+                            menu: parent,
+                            direction,
+                        };
+                    }
+                }
+                let to = or_none!(to);
+                // `from` is longer than `focused` when the request comes from a submenu.
+                let enters_content = !setting.scope_stays_on_tabs || from.len().get() > 1;
                 let extra = match child_menu(*to, queries) {
-                    Some((_, menu, _)) => focus_deep(menu, queries),
-                    None => Vec::new(),
+                    Some((_, menu, _)) if enters_content => focus_deep(menu, queries),
+                    _ => Vec::new(),
                 };
                 let to = (extra, *to).into();
-                NavEvent::FocusChanged { to, from }
+                NavEvent::path_changed(to, from)
             }
         }
         FocusOn(new_to_focus) => {
@@ -793,18 +2523,27 @@ fn resolve<STGY: MenuNavigationStrategy>(
             if matches!(focusable, Ok((_, f)) if f.state() == Blocked) {
                 return NavEvent::NoChanges { from, request };
             }
-            // assumption here is that there is a common ancestor
-            // though nothing really breaks if there isn't
+            // When `focused` and `new_to_focus` are in disjoint menu trees,
+            // there is no common tail to trim: the whole `from` path is
+            // deactivated and the whole `to` path activated.
             let mut from = queries.root_path(focused);
             let mut to = queries.root_path(new_to_focus);
             trim_common_tail(&mut from, &mut to);
             if from == to {
                 NavEvent::NoChanges { from, request }
             } else {
-                NavEvent::FocusChanged { from, to }
+                NavEvent::path_changed(to, from)
+            }
+        }
+        PeekFocus(to_peek) => {
+            let focusable = queries.focusables.get(to_peek);
+            if !matches!(focusable, Ok((_, f)) if f.state() != Blocked) || to_peek == focused {
+                return NavEvent::NoChanges { from, request };
             }
+            NavEvent::focus_changed(to_peek, NonEmpty::new(focused))
         }
         Unlock => {
+            lock.unlock_with = None;
             if let Some(lock_entity) = lock.lock_reason.take() {
                 NavEvent::Unlocked(lock_entity)
             } else {
@@ -815,30 +2554,106 @@ fn resolve<STGY: MenuNavigationStrategy>(
     }
 }
 
+/// The [`ScopeDirection`] matching `direction` in a [`NavStrategy::Sequential`] menu.
+///
+/// [`ScopeDirection`]: events::ScopeDirection
+fn sequential_direction(direction: events::Direction) -> Option<events::ScopeDirection> {
+    use events::{Direction::*, ScopeDirection::*};
+    match direction {
+        South | East | SouthEast => Some(Next),
+        North | West | NorthWest => Some(Previous),
+        NorthEast | SouthWest => None,
+    }
+}
+
+/// Like [`MenuNavigationStrategy::resolve_2d`] with cycling, but when wrapping,
+/// focus the last focused entity of the [wrap region] if any.
+///
+/// [wrap region]: MenuNavigationStrategy::wrap_region
+fn resolve_2d_dormant<'a, STGY: MenuNavigationStrategy>(
+    strategy: &STGY,
+    focused: Entity,
+    direction: events::Direction,
+    menu: &TreeMenu,
+    siblings: &'a [Entity],
+) -> Option<&'a Entity> {
+    if let Some(to) = strategy.resolve_2d(focused, direction, false, siblings) {
+        return Some(to);
+    }
+    let wrapped_to = strategy.resolve_2d(focused, direction, true, siblings)?;
+    let region = strategy.wrap_region(*wrapped_to, direction, siblings);
+    let is_dormant = |e: &&Entity| region.contains(e) && **e != focused;
+    let dormant = menu.dormant.iter().find(is_dormant);
+    let dormant = dormant.and_then(|d| siblings.iter().find(|e| *e == d));
+    dormant.or(Some(wrapped_to))
+}
+
+/// Like [`MenuNavigationStrategy::resolve_2d`] with wrapping, but wrapping to
+/// the next row or column, see [`MenuSetting::snake_wrap`].
+fn resolve_2d_snake<'a, STGY: MenuNavigationStrategy>(
+    strategy: &STGY,
+    focused: Entity,
+    direction: events::Direction,
+    siblings: &'a [Entity],
+) -> Option<&'a Entity> {
+    use events::Direction::{East, North, South, West};
+
+    if let Some(to) = strategy.resolve_2d(focused, direction, false, siblings) {
+        return Some(to);
+    }
+    let wrapped_to = strategy.resolve_2d(focused, direction, true, siblings)?;
+    let next_line = match direction {
+        East => South,
+        West => North,
+        South => East,
+        North => West,
+        _ => return Some(wrapped_to),
+    };
+    let next = strategy.resolve_2d(*wrapped_to, next_line, true, siblings);
+    next.or(Some(wrapped_to))
+}
+
 /// Replaces [`MenuBuilder`]s with proper [`TreeMenu`]s.
+///
+/// When the menu already has a [`TreeMenu`], for example when spawned from
+/// a scene saved with the navigation state, the `TreeMenu` takes precedence
+/// and the `MenuBuilder` is only removed.
+#[allow(clippy::type_complexity)]
 pub(crate) fn insert_tree_menus(
     mut commands: Commands,
-    builders: Query<(Entity, &MenuBuilder), With<MenuSetting>>,
+    builders: Query<(Entity, &MenuBuilder, Option<&TreeMenu>), With<MenuSetting>>,
     queries: NavQueries,
 ) {
-    use FocusState::{Active, Focused, Prioritized};
     let mut inserts = Vec::new();
     let no_focus_msg = "Within a menu built with MenuBuilder, there must be at least one entity \
          with the Focusable component, none were found";
-    for (entity, builder) in &builders {
-        let children = queries.children.focusables_of(entity);
-        let child = children
-            .iter()
-            .find_map(|e| {
-                let (_, focusable) = queries.focusables.get(*e).ok()?;
-                matches!(focusable.state, Prioritized | Active | Focused).then_some(e)
-            })
-            .unwrap_or_else(|| children.first().expect(no_focus_msg));
+    for (entity, builder, existing) in &builders {
+        let child = &queries.pick_active_child(entity).expect(no_focus_msg);
+        if let Some(existing) = existing {
+            let children = queries.children.focusables_of(entity);
+            let builder_parent: Result<Option<Entity>, _> = builder.try_into();
+            if matches!(builder_parent, Ok(parent) if parent != existing.focus_parent) {
+                warn!(
+                    "Menu {entity:?} has both a TreeMenu and a MenuBuilder with different \
+                    parents, the MenuBuilder is ignored."
+                );
+            }
+            if !children.contains(&existing.active_child) {
+                warn!(
+                    "Menu {entity:?} has a TreeMenu with an active child not in the menu, \
+                    replacing it with {child:?}."
+                );
+                let menu = TreeMenu {
+                    active_child: *child,
+                    ..existing.clone()
+                };
+                inserts.push((entity, menu));
+            }
+            commands.entity(entity).remove::<MenuBuilder>();
+            continue;
+        }
         if let Ok(focus_parent) = builder.try_into() {
-            let menu = TreeMenu {
-                focus_parent,
-                active_child: *child,
-            };
+            let menu = TreeMenu::new(focus_parent, *child);
             inserts.push((entity, menu));
             commands.entity(entity).remove::<MenuBuilder>();
             debug!("Associated {entity:?} with a parent focusable.");
@@ -847,59 +2662,253 @@ pub(crate) fn insert_tree_menus(
     commands.insert_or_spawn_batch(inserts);
 }
 
+/// Warn about new menus with more than one [`FocusState::Prioritized`] focusable.
+///
+/// Which of them is focused when entering the menu is unspecified,
+/// this is most likely a misuse of [`Focusable::prioritized`].
+#[cfg(debug_assertions)]
+pub(crate) fn warn_multiple_prioritized(
+    new_menus: Query<Entity, Added<TreeMenu>>,
+    queries: NavQueries,
+) {
+    for menu in &new_menus {
+        let prioritized: Vec<_> = queries
+            .children
+            .focusables_of(menu)
+            .into_iter()
+            .filter(|e| {
+                let focusable = queries.focusables.get(*e);
+                matches!(focusable, Ok((_, f)) if f.state() == FocusState::Prioritized)
+            })
+            .collect();
+        if prioritized.len() > 1 {
+            warn!(
+                "Menu {menu:?} has multiple prioritized focusables: {prioritized:?}, \
+                which one is focused when entering the menu is unspecified."
+            );
+        }
+    }
+}
+
 /// System to set the first [`Focusable`] to [`FocusState::Focused`]
 /// when no navigation has been done yet.
 ///
 /// This also sets `Active` state and `active_child` of menus leading
-/// to the current focusable.
-pub(crate) fn set_first_focused(
+/// to the current focusable, and sends [`NavEvent::InitiallyFocused`]
+/// followed by [`NavEvent::TreeInitialized`].
+///
+/// The [`InitialFocus`] is picked first, if it exists.
+///
+/// When nothing can be focused anymore, sends a [`NavEvent::FocusLost`]
+/// and releases the [`NavLock`] if a [`Focusable`] set it.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn set_first_focused<STGY: SystemParam>(
     has_focused: Query<(), With<Focused>>,
     mut queries: ParamSet<(NavQueries, MutQueries)>,
+    mquery: StaticSystemParam<STGY>,
+    initial: Option<Res<InitialFocus>>,
+    mut lock: ResMut<NavLock>,
+    mut had_focus: Local<bool>,
     mut events: EventWriter<NavEvent>,
-) {
-    if has_focused.is_empty() {
-        if let Some(to_focus) = queries.p0().pick_first_focused() {
-            let breadcrumb = queries.p0().root_path(to_focus);
-            queries.p1().update_focus(&[], &breadcrumb);
-            events.send(NavEvent::InitiallyFocused(to_focus));
+) where
+    for<'w, 's> SystemParamItem<'w, 's, STGY>: MenuNavigationStrategy,
+{
+    if !has_focused.is_empty() {
+        *had_focus = true;
+        return;
+    }
+    let initial = initial.and_then(|initial| queries.p0().with_focus_id(initial.0));
+    let picked = || queries.p0().pick_first_focused(&*mquery);
+    if let Some(to_focus) = initial.or_else(picked) {
+        let breadcrumb = queries.p0().root_path(to_focus);
+        queries.p1().update_focus(&[], &breadcrumb);
+        *had_focus = true;
+        events.send(NavEvent::InitiallyFocused(to_focus));
+        events.send(NavEvent::TreeInitialized { path: breadcrumb });
+    } else if *had_focus {
+        *had_focus = false;
+        if matches!(lock.lock_reason, Some(LockReason::Focusable(_))) {
+            lock.lock_reason = None;
         }
+        events.send(NavEvent::FocusLost);
+    }
+}
+
+/// Update the [`SkipOffscreen`] viewport from the camera showing UI,
+/// see [`NavCamera`].
+#[cfg(feature = "bevy_ui")]
+pub(crate) fn update_viewport(
+    mut skip_offscreen: ResMut<SkipOffscreen>,
+    mut warned: Local<bool>,
+    cameras: Query<(&Camera, Option<&UiCameraConfig>, Has<NavCamera>)>,
+) {
+    let Some(camera) = nav_camera(&cameras, &mut warned) else {
+        return;
+    };
+    let viewport = camera.logical_viewport_rect();
+    if viewport.is_some() && skip_offscreen.viewport != viewport {
+        skip_offscreen.viewport = viewport;
+    }
+}
+
+/// Focus the nearest visible [`Focusable`] in the same menu
+/// when the focused one isn't visible anymore, see [`SkipOffscreen`].
+#[cfg(feature = "bevy_ui")]
+pub(crate) fn refocus_offscreen(
+    projection: UiProjectionQuery,
+    queries: NavQueries,
+    focused: Query<(Entity, &GlobalTransform), With<Focused>>,
+    mut requests: EventWriter<NavRequest>,
+) {
+    let Ok((focused, transform)) = focused.get_single() else {
+        return;
+    };
+    if projection.is_visible(focused) {
+        return;
+    }
+    let menu = queries.parent_menu(focused).map(|(menu, ..)| menu);
+    let mut siblings = queries.siblings_in(menu);
+    siblings.retain(|e| *e != focused && projection.is_visible(*e));
+    if let Some(nearest) = projection.nearest_to(transform.translation().xy(), &siblings) {
+        requests.send(NavRequest::FocusOn(*nearest));
     }
 }
 
 pub(crate) fn consistent_menu(
     updated_focusables: Query<(Entity, &Focusable), Changed<Focusable>>,
-    children: ChildQueries,
-    mut menus: Query<(Entity, &mut TreeMenu)>,
+    mut queries: ParamSet<(NavQueries, Query<&mut TreeMenu>)>,
+    mut requests: EventWriter<NavRequest>,
 ) {
+    use FocusState::{Active, Blocked, Focused};
+
+    let mut new_actives = Vec::new();
     for (entity, updated) in &updated_focusables {
-        if updated.state() != FocusState::Blocked {
-            continue;
-        }
-        for (menu_entity, mut menu) in &mut menus {
-            if menu.active_child != entity {
-                continue;
+        let queries = queries.p0();
+        match updated.state() {
+            Blocked => {
+                let leads_to = |m: &(_, &TreeMenu, _)| m.1.active_child == entity;
+                // There is a unique menu that leads to the changed entity.
+                let Some((menu, ..)) = queries.menus.iter().find(leads_to) else {
+                    continue;
+                };
+                if let Some(new_active) = queries.children.focusables_of(menu).first() {
+                    new_actives.push((menu, *new_active));
+                }
             }
-            if let Some(new_active) = children.focusables_of(menu_entity).first().copied() {
-                menu.active_child = new_active;
+            Active | Focused if updated.block_pending => {
+                // Move focus away, `update_focus` then blocks `entity`.
+                let menu = queries.parent_menu(entity).map(|m| m.0);
+                let siblings = queries.siblings_in(menu);
+                let next = siblings.iter().position(|e| *e == entity);
+                let next = next.and_then(|i| siblings.get(i + 1));
+                let other = || siblings.iter().rev().find(|e| **e != entity);
+                match next.or_else(other) {
+                    Some(sibling) => requests.send(NavRequest::FocusOn(*sibling)),
+                    None => debug!(
+                        "No sibling to move focus to from {entity:?}, \
+                        it will be blocked once it loses focus."
+                    ),
+                }
             }
-            // We found the unique menu that leads to the changed entity
-            // continue to check for next changed focusable.
-            break;
+            _ => {}
+        }
+    }
+    let mut menus = queries.p1();
+    for (menu, new_active) in new_actives {
+        if let Ok(mut menu) = menus.get_mut(menu) {
+            menu.active_child = new_active;
         }
     }
 }
 
+/// [`GenericNavigationPlugin`] settings used by [`listen_nav_requests`].
+///
+/// [`GenericNavigationPlugin`]: crate::GenericNavigationPlugin
+#[derive(Resource, Debug, Clone, Copy)]
+pub(crate) struct NavSettings {
+    /// Whether to send [`NavEvent::NoChanges`] for requests other
+    /// than [`NavRequest::Action`].
+    ///
+    /// Set with [`GenericNavigationPlugin::emit_no_changes`].
+    ///
+    /// [`GenericNavigationPlugin::emit_no_changes`]: crate::GenericNavigationPlugin::emit_no_changes
+    pub(crate) emit_no_changes: bool,
+    /// For how many frames without any focusable to only `debug!` log
+    /// [`NavRequest`]s rather than warn.
+    ///
+    /// Set with [`GenericNavigationPlugin::no_focusable_grace`].
+    ///
+    /// [`GenericNavigationPlugin::no_focusable_grace`]: crate::GenericNavigationPlugin::no_focusable_grace
+    pub(crate) no_focusable_grace: u32,
+    /// The schedule menus are built in, read by [`AutoFocusableExt`].
+    ///
+    /// `PreUpdate` unless set with [`GenericNavigationPlugin::in_schedule`].
+    ///
+    /// [`AutoFocusableExt`]: crate::systems::AutoFocusableExt
+    /// [`GenericNavigationPlugin::in_schedule`]: crate::GenericNavigationPlugin::in_schedule
+    pub(crate) menu_schedule: InternedScheduleLabel,
+}
+
+/// The opt-in resources [`listen_nav_requests`] reads or updates
+/// only if they exist.
+#[derive(SystemParam)]
+pub(crate) struct OptInResources<'w> {
+    summary: Option<ResMut<'w, FrameNavSummary>>,
+    trail: Option<ResMut<'w, ActiveTrail>>,
+    scope_stack: Option<Res<'w, NavScopeStack>>,
+    coalesce: Option<Res<'w, CoalesceNavRequests>>,
+}
+
+/// A [`NavRequest::PeekFocus`] in progress.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Peek {
+    peeked: Entity,
+    peeked_state: FocusState,
+    previous: Entity,
+}
+
+/// How many nested menus can be entered in a row
+/// through [`MenuSetting::auto_activate_single`].
+const MAX_AUTO_ACTIVATE_DEPTH: usize = 16;
+
 /// Listen to [`NavRequest`] and update the state of [`Focusable`] entities
 /// when relevant.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn listen_nav_requests<STGY: SystemParam>(
     mut queries: ParamSet<(NavQueries, MutQueries)>,
     mquery: StaticSystemParam<STGY>,
     mut lock: ResMut<NavLock>,
     mut requests: EventReader<NavRequest>,
     mut events: EventWriter<NavEvent>,
+    opt_in: OptInResources,
+    #[cfg(feature = "history")] mut history: Option<ResMut<events::NavHistory>>,
+    settings: Res<NavSettings>,
+    transforms: Query<&GlobalTransform>,
+    has_focused: Query<(), With<Focused>>,
+    mut peek: Local<Option<Peek>>,
+    mut unfocused_frames: Local<u32>,
 ) where
     for<'w, 's> SystemParamItem<'w, 's, STGY>: MenuNavigationStrategy,
 {
+    let OptInResources {
+        summary,
+        trail,
+        scope_stack,
+        coalesce,
+    } = opt_in;
+    *unfocused_frames = match has_focused.is_empty() {
+        true => unfocused_frames.saturating_add(1),
+        false => 0,
+    };
+    // Focus only changes through `NavRequest`s, unless `set_first_focused`
+    // just picked a focusable (its commands are not applied yet, so
+    // there is no `Focused` entity) in which case we must update the summary.
+    if requests.is_empty() && !has_focused.is_empty() {
+        if let Some(mut summary) = summary.filter(|s| s.changed) {
+            summary.changed = false;
+        }
+        return;
+    }
     let no_focused = "Tried to execute a NavRequest \
             when no focusables exist, \
             NavRequest does nothing if \
@@ -907,27 +2916,216 @@ pub(crate) fn listen_nav_requests<STGY: SystemParam>(
 
     // Cache focus result from previous iteration to avoid re-running costly `pick_first_focused`
     let mut computed_focused = None;
-    for request in requests.read() {
-        if lock.is_locked() && *request != NavRequest::Unlock {
-            continue;
+    let mut selections: Vec<(Entity, bool)> = Vec::new();
+    let position = |entity: &Entity| Some(transforms.get(*entity).ok()?.translation().xy());
+    let mut requests: Vec<_> = requests.read().copied().collect();
+    // NOTE: this is a stable sort, keeping the sending order within categories.
+    requests.sort_by_key(NavRequest::processing_order);
+    if coalesce.is_some() {
+        requests.dedup();
+    }
+    for mut request in requests {
+        if lock.is_locked() {
+            if !lock.unlocks(request) {
+                continue;
+            }
+            request = NavRequest::Unlock;
+        }
+        #[cfg(feature = "history")]
+        if let Some(history) = history.as_mut() {
+            history.begin(request);
+        }
+        let mut send = |event: NavEvent| {
+            #[cfg(feature = "history")]
+            if let Some(history) = history.as_mut() {
+                history.record(&event);
+            }
+            if let NavEvent::NoChanges { request, .. } = &event {
+                if !settings.emit_no_changes && !request.is_action() {
+                    return;
+                }
+            }
+            events.send(event);
+        };
+        if let Some(ended) = peek.take() {
+            if request == NavRequest::PeekFocus(ended.peeked) {
+                *peek = Some(ended);
+                continue;
+            }
+            queries.p1().end_peek(ended);
+            let within_menu = queries.p0().same_menu(ended.peeked, ended.previous);
+            send(NavEvent::FocusChanged {
+                to: NonEmpty::new(ended.previous),
+                from: NonEmpty::new(ended.peeked),
+                from_position: position(&ended.peeked),
+                to_position: position(&ended.previous),
+                within_menu,
+            });
+            let previous_exists = queries.p0().focusables.contains(ended.previous);
+            computed_focused = previous_exists.then_some(ended.previous);
         }
         // We use `pick_first_focused` instead of `Focused` component for first
         // iteration because `set_first_focused` just before `listen_nav_request`
         // without a command flush in-between.
-        let picked = || queries.p0().pick_first_focused();
+        let picked = || queries.p0().pick_first_focused(&*mquery);
         let focused = match computed_focused.or_else(picked) {
             Some(focused) => focused,
+            // Requests sent while the UI is loading are expected, only
+            // warn if focusables still do not exist after a while.
+            None if *unfocused_frames <= settings.no_focusable_grace => {
+                debug!(no_focused);
+                return;
+            }
             None => {
                 warn!(no_focused);
                 return;
             }
         };
-        let from = Vec::new();
-        let event = resolve(focused, *request, &queries.p0(), &mut lock, from, &*mquery);
-        if let NavEvent::FocusChanged { to, from } = &event {
-            computed_focused = Some(queries.p1().update_focus(from, to));
-        };
-        events.send(event);
+        if let Some((menu, engaged)) = queries.p0().engage_state(focused) {
+            use NavRequest::{ActionFrom, Cancel, Move, MoveBy};
+            let engages = match request {
+                Move(_) | MoveBy(_) if !engaged => {
+                    let from = NonEmpty::new(focused);
+                    send(NavEvent::NoChanges { from, request });
+                    continue;
+                }
+                Cancel if engaged => Some(false),
+                request if request.is_action() && !engaged => Some(true),
+                _ => None,
+            };
+            if let Some(engaged) = engages {
+                queries.p1().set_engaged(menu, engaged);
+                send(NavEvent::Engaged { menu, engaged });
+                // Clicks pick the element themselves, they also activate it.
+                if request != ActionFrom(InputSource::Pointer) {
+                    continue;
+                }
+            }
+        }
+        let menu = queries.p0().parent_menu(focused);
+        if let (NavRequest::Move(direction), Some((menu, tree, _))) = (request, menu) {
+            queries.p1().set_moved_since_focus(menu, true);
+            let first_move = !tree.moved_since_focus;
+            if first_move && queries.p0().wraps_first_move(&*mquery, focused, direction) {
+                let from = NonEmpty::new(focused);
+                send(NavEvent::NoChanges { from, request });
+                continue;
+            }
+        }
+        let (mut focused, mut request) = (focused, request);
+        for depth in 0.. {
+            let from = Vec::new();
+            let mut event = resolve(focused, request, &queries.p0(), &mut lock, from, &*mquery);
+            let scope = scope_stack.as_ref().and_then(|stack| stack.top());
+            if let (NavEvent::FocusChanged { to, from, .. }, Some(scope)) = (&event, scope) {
+                if !queries.p0().is_within(*to.first(), scope) {
+                    let from = from.clone();
+                    event = NavEvent::NoChanges { from, request };
+                }
+            }
+            let mut auto_activate = false;
+            let mut disengaged = Vec::new();
+            if let NavEvent::FocusChanged {
+                to,
+                from,
+                from_position,
+                to_position,
+                within_menu,
+            } = &mut event
+            {
+                *from_position = position(from.first());
+                *to_position = position(to.first());
+                *within_menu = queries.p0().same_menu(*from.first(), *to.first());
+                if let NavRequest::PeekFocus(to_peek) = request {
+                    *peek = Some(queries.p1().peek_focus(focused, to_peek));
+                    send(event);
+                    break;
+                }
+                let (_, left) = from.split_last();
+                let forgotten = queries.p0().forgetful_menus(left, to);
+                disengaged = queries.p0().disengaged_menus(from, to);
+                focused = queries.p1().update_focus(from, to);
+                for (menu, entry) in forgotten {
+                    queries.p1().forget(menu, entry);
+                }
+                for &menu in &disengaged {
+                    queries.p1().set_engaged(menu, false);
+                }
+                computed_focused = Some(focused);
+                let moved = matches!(request, NavRequest::Move(_) | NavRequest::MoveBy(_));
+                if !(moved && *within_menu) {
+                    if let Some((menu, ..)) = queries.p0().parent_menu(focused) {
+                        queries.p1().set_moved_since_focus(menu, false);
+                    }
+                }
+                auto_activate = request.is_action() && queries.p0().auto_activates(focused);
+            };
+            if let NavEvent::Toggled { entity, value } = event {
+                queries.p1().set_toggle(entity, value);
+            }
+            if let NavEvent::SelectionChanged { entity, selected } = &mut event {
+                // `Selected` is only added when commands are applied,
+                // toggling again in the same frame must undo this change.
+                if let Some((_, previous)) = selections.iter().rev().find(|s| s.0 == *entity) {
+                    *selected = !previous;
+                }
+                selections.push((*entity, *selected));
+                queries.p1().set_selected(*entity, *selected);
+            }
+            let activated = matches!(
+                event,
+                NavEvent::NoChanges {
+                    request: NavRequest::Action,
+                    ..
+                }
+            );
+            send(event);
+            for menu in disengaged {
+                send(NavEvent::Engaged {
+                    menu,
+                    engaged: false,
+                });
+            }
+            if activated && queries.p0().closes_on_action(focused) {
+                request = NavRequest::Cancel;
+                continue;
+            }
+            if !auto_activate {
+                break;
+            }
+            if depth == MAX_AUTO_ACTIVATE_DEPTH {
+                warn!(
+                    "Stopped auto-activating single-focusable menus after \
+                    {MAX_AUTO_ACTIVATE_DEPTH} menus, make sure `MenuSetting::auto_activate_single` \
+                    doesn't create a loop."
+                );
+                break;
+            }
+            request = NavRequest::Action;
+        }
+    }
+    if summary.is_none() && trail.is_none() {
+        return;
+    }
+    let final_focus = computed_focused.or_else(|| queries.p0().pick_first_focused(&*mquery));
+    if let Some(mut summary) = summary {
+        let changed = final_focus != summary.final_focus;
+        // Avoid triggering change detection when nothing changed.
+        if changed || summary.changed {
+            let depth = |focus| queries.p0().root_path(focus).len().get() - 1;
+            *summary = FrameNavSummary {
+                final_focus,
+                changed,
+                menu_depth: final_focus.map_or(0, depth),
+            };
+        }
+    }
+    if let Some(mut trail) = trail {
+        let path = final_focus.map(|focus| queries.p0().root_path(focus));
+        let path: Vec<_> = path.iter().flat_map(|p| p.iter().rev().copied()).collect();
+        if trail.0 != path {
+            trail.0 = path;
+        }
     }
 }
 
@@ -947,30 +3145,67 @@ pub(crate) fn parent_menu(
     focusable: Entity,
     queries: &NavQueries,
 ) -> Option<(Entity, TreeMenu, MenuSetting)> {
-    let parent = queries.parents.get(focusable).ok()?.get();
-    match queries.menus.get(parent) {
-        Ok((_, tree, setting)) => Some((parent, tree.clone(), *setting)),
-        Err(_) => parent_menu(parent, queries),
-    }
+    queries.parent_menu(focusable)
 }
 
 impl<'w, 's> ChildQueries<'w, 's> {
+    /// Whether `entity` is accepted by the user-defined [`FocusableFilter`].
+    pub(crate) fn passes_filter(&self, entity: Entity) -> bool {
+        self.filter
+            .as_ref()
+            .map_or(true, |filter| filter.0.is_focusable(entity))
+    }
+
+    /// Whether `entity` can be reached without a pointer, it passes
+    /// the [`FocusableFilter`] and is not [`PointerOnly`].
+    pub(crate) fn is_navigable(&self, entity: Entity) -> bool {
+        self.passes_filter(entity) && !self.pointer_only.contains(entity)
+    }
+
+    /// The menu `entity` is explicitly a [`MenuMember`] of, if any.
+    pub(crate) fn member_of(&self, entity: Entity) -> Option<Entity> {
+        let (_, member) = self.members.get(entity).ok()?;
+        self.is_menu.contains(member.0).then_some(member.0)
+    }
+
     /// All sibling [`Focusable`]s within a single [`TreeMenu`].
+    ///
+    /// This includes the [`MenuMember`]s of `menu`, after the ones
+    /// in the bevy hierarchy.
     pub(crate) fn focusables_of(&self, menu: Entity) -> Vec<Entity> {
+        let mut focusables = self.descendant_focusables(menu, menu);
+        let is_member = |(e, member): &(Entity, &MenuMember)| {
+            member.0 == menu && self.is_candidate(*e) && !focusables.contains(e)
+        };
+        let members: Vec<_> = self.members.iter().filter(is_member).collect();
+        focusables.extend(members.into_iter().map(|(e, _)| e));
+        focusables
+    }
+
+    /// Whether `entity` is a non-blocked navigable [`Focusable`].
+    fn is_candidate(&self, entity: Entity) -> bool {
         use FocusState::Blocked;
-        let is_focusable = |e: &&_| {
-            self.is_focusable
-                .get(**e)
-                .map_or(false, |f| f.state != Blocked)
+        self.is_focusable
+            .get(entity)
+            .is_ok_and(|f| f.state != Blocked)
+            && self.is_navigable(entity)
+    }
+
+    /// [`Focusable`]s of `menu` in the bevy hierarchy under `entity`.
+    fn descendant_focusables(&self, menu: Entity, entity: Entity) -> Vec<Entity> {
+        let in_menu = |e: &&_| match self.member_of(**e) {
+            Some(member_of) => member_of == menu,
+            None => true,
         };
-        match self.children.get(menu) {
+        let is_focusable = |e: &&_| self.is_candidate(**e) && in_menu(e);
+        match self.children.get(entity) {
             Ok(direct_children) => {
                 let focusables = direct_children.iter().filter(is_focusable).cloned();
                 let transitive_focusables = direct_children
                     .iter()
                     .filter(|e| !self.is_focusable.contains(**e))
                     .filter(|e| !self.is_menu.contains(**e))
-                    .flat_map(|e| self.focusables_of(*e));
+                    .flat_map(|e| self.descendant_focusables(menu, *e));
                 focusables.chain(transitive_focusables).collect()
             }
             Err(_) => Vec::new(),
@@ -978,28 +3213,6 @@ impl<'w, 's> ChildQueries<'w, 's> {
     }
 }
 
-/// Remove all mutually identical elements at the end of `v1` and `v2`.
-fn trim_common_tail<T: PartialEq>(v1: &mut NonEmpty<T>, v2: &mut NonEmpty<T>) {
-    let mut i1 = v1.len().get() - 1;
-    let mut i2 = v2.len().get() - 1;
-    loop {
-        if v1[i1] != v2[i2] {
-            // unwraps: any usize + 1 (saturating) is NonZero
-            let l1 = NonZeroUsize::new(i1.saturating_add(1)).unwrap();
-            let l2 = NonZeroUsize::new(i2.saturating_add(1)).unwrap();
-            v1.truncate(l1);
-            v2.truncate(l2);
-            return;
-        } else if i1 != 0 && i2 != 0 {
-            i1 -= 1;
-            i2 -= 1;
-        } else {
-            // There is no changes to be made to the input vectors
-            return;
-        }
-    }
-}
-
 /// Navigate downward the menu hierarchy, traversing all prioritized children.
 fn focus_deep<'a>(mut menu: &'a TreeMenu, queries: &'a NavQueries) -> Vec<Entity> {
     let mut ret = Vec::with_capacity(4);
@@ -1012,37 +3225,3 @@ fn focus_deep<'a>(mut menu: &'a TreeMenu, queries: &'a NavQueries) -> Vec<Entity
         };
     }
 }
-
-/// Cycle through a [scoped menu](MenuSetting::scope) according to menu settings.
-///
-/// Returns the index of the element to focus according to `direction`.
-/// Cycles if `cycles` and goes over `max_value` or goes bellow 0.
-/// `None` if the direction is a dead end.
-fn resolve_index(
-    from: usize,
-    cycles: bool,
-    direction: events::ScopeDirection,
-    max_value: usize,
-) -> Option<usize> {
-    use events::ScopeDirection::*;
-    match (direction, from) {
-        (Previous, 0) => cycles.then_some(max_value),
-        (Previous, from) => Some(from - 1),
-        (Next, from) if from == max_value => cycles.then_some(0),
-        (Next, from) => Some(from + 1),
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::trim_common_tail;
-    #[test]
-    fn test_trim_common_tail() {
-        use non_empty_vec::ne_vec;
-        let mut v1 = ne_vec![1, 2, 3, 4, 5, 6, 7];
-        let mut v2 = ne_vec![3, 2, 1, 4, 5, 6, 7];
-        trim_common_tail(&mut v1, &mut v2);
-        assert_eq!(v1, ne_vec![1, 2, 3]);
-        assert_eq!(v2, ne_vec![3, 2, 1]);
-    }
-}
@@ -0,0 +1,85 @@
+//! Save and restore the navigation state with bevy scenes.
+//!
+//! Requires the `bevy_reflect` feature.
+use bevy::ecs::{entity::Entity, prelude::World};
+use bevy::scene::{DynamicScene, DynamicSceneBuilder, SceneSpawnError};
+use bevy::utils::HashMap;
+
+use crate::menu::{MenuMember, MenuSetting, NavStrategy};
+use crate::resolve::{
+    ActionSources, ActionTarget, FocusId, FocusState, Focusable, Focused, NavLinks, NavLock,
+    PointerOnly, Selectable, Selected, Toggleable, TreeMenu,
+};
+
+/// Snapshot the state of all [`Focusable`]s and menus of `world`
+/// into a [`DynamicScene`].
+///
+/// The scene contains every entity with a [`Focusable`] or [`MenuSetting`],
+/// but only the navigation components, and the [`NavLock`] resource.
+/// Use [`restore_nav`] to apply it back to the same world, or spawn it
+/// as any other scene to copy it. [`Entity`] references in menus are mapped
+/// to the spawned entities.
+///
+/// Note that a [`NavLock`] held by a focusable still points to the original
+/// entity, bevy doesn't support entity mapping on resources.
+pub fn snapshot_nav(world: &World) -> DynamicScene {
+    let entities = world
+        .iter_entities()
+        .filter(|e| e.contains::<Focusable>() || e.contains::<MenuSetting>())
+        .map(|e| e.id());
+    DynamicSceneBuilder::from_world(world)
+        .deny_all()
+        .allow::<Focusable>()
+        .allow::<TreeMenu>()
+        .allow::<MenuSetting>()
+        .allow::<MenuMember>()
+        .allow::<NavStrategy>()
+        .allow::<Toggleable>()
+        .allow::<Selectable>()
+        .allow::<Selected>()
+        .allow::<ActionSources>()
+        .allow::<ActionTarget>()
+        .allow::<FocusId>()
+        .allow::<NavLinks>()
+        .allow::<PointerOnly>()
+        .deny_all_resources()
+        .allow_resource::<NavLock>()
+        .extract_entities(entities)
+        .extract_resources()
+        .build()
+}
+
+/// Apply a scene created with [`snapshot_nav`] to the `world` it was taken from.
+///
+/// Entities of the snapshot that were despawned since are spawned again,
+/// without their other components or place in the hierarchy.
+///
+/// # Errors
+///
+/// When a navigation type is not registered in the world's `AppTypeRegistry`,
+/// this is the case when the navigation plugin wasn't added.
+pub fn restore_nav(scene: &DynamicScene, world: &mut World) -> Result<(), SceneSpawnError> {
+    let mut entity_map: HashMap<_, _> = scene
+        .entities
+        .iter()
+        .filter_map(|e| world.get_entity(e.entity).map(|_| (e.entity, e.entity)))
+        .collect();
+    scene.write_to_world(world, &mut entity_map)?;
+
+    let mut focusables = world.query::<(Entity, &Focusable, Option<&Focused>)>();
+    let (mut to_mark, mut to_unmark) = (Vec::new(), Vec::new());
+    for (entity, focusable, focused) in focusables.iter(world) {
+        match (focusable.state() == FocusState::Focused, focused.is_some()) {
+            (true, false) => to_mark.push(entity),
+            (false, true) => to_unmark.push(entity),
+            _ => {}
+        }
+    }
+    for entity in to_mark {
+        world.entity_mut(entity).insert(Focused);
+    }
+    for entity in to_unmark {
+        world.entity_mut(entity).remove::<Focused>();
+    }
+    Ok(())
+}
@@ -1,14 +1,21 @@
 //! System for the navigation tree and default input systems to get started.
 use crate::{
-    events::{Direction, NavRequest, ScopeDirection},
-    resolve::Focused,
+    events::{Direction, InputSource, NavRequest, ScopeDirection},
+    generic::type_ahead_index,
+    menu::MenuSetting,
+    resolve::{Focusable, Focused},
 };
 
 #[cfg(feature = "bevy_ui")]
-use crate::resolve::ScreenBoundaries;
-use bevy::prelude::*;
+use crate::resolve::{nav_camera, NavCamera, ScreenBoundaries};
+#[cfg(feature = "bevy_ui")]
+use bevy::ecs::query::Has;
+#[cfg(all(feature = "pointer_focus", feature = "bevy_reflect"))]
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::ecs::schedule::ScheduleLabel;
 #[cfg(feature = "bevy_reflect")]
 use bevy::{ecs::reflect::ReflectResource, reflect::Reflect};
+use bevy::{prelude::*, window::ReceivedCharacter};
 #[cfg(feature = "pointer_focus")]
 use bevy_mod_picking::prelude::*;
 
@@ -22,6 +29,23 @@ pub struct InputMapping {
     pub gamepads: Vec<Gamepad>,
     /// Deadzone on the gamepad left stick for ui navigation
     pub joystick_ui_deadzone: f32,
+    /// When to send [`NavRequest::Move`]s while the gamepad stick is held.
+    pub stick_mode: StickMode,
+    /// Whether the gamepad stick moves in diagonal directions too,
+    /// such as [`Direction::NorthEast`].
+    ///
+    /// Each of the eight directions then covers a 45° sector of the stick,
+    /// instead of 90° for the four cardinal directions.
+    /// Make sure your [`MenuNavigationStrategy`] handles diagonals.
+    ///
+    /// [`MenuNavigationStrategy`]: crate::resolve::MenuNavigationStrategy
+    pub diagonal_navigation: bool,
+    /// How far past the edge of its sector, in radians, the gamepad stick
+    /// must go before switching from the held direction to the adjacent one.
+    ///
+    /// Prevents jittering between two directions when holding the stick
+    /// close to the boundary of their sectors. `0.0` disables it.
+    pub stick_hysteresis: f32,
     /// X axis of gamepad stick
     pub move_x: GamepadAxisType,
     /// Y axis of gamepad stick
@@ -36,6 +60,10 @@ pub struct InputMapping {
     pub down_button: GamepadButtonType,
     /// Gamepad button for [`NavRequest::Action`]
     pub action_button: GamepadButtonType,
+    /// Gamepad button for [`NavRequest::SecondaryAction`]
+    pub secondary_action_button: GamepadButtonType,
+    /// Gamepad button for [`NavRequest::TertiaryAction`]
+    pub tertiary_action_button: GamepadButtonType,
     /// Gamepad button for [`NavRequest::Cancel`]
     pub cancel_button: GamepadButtonType,
     /// Gamepad button for [`ScopeDirection::Previous`] [`NavRequest::ScopeMove`]
@@ -62,6 +90,10 @@ pub struct InputMapping {
     pub key_down_alt: KeyCode,
     /// Keyboard key for [`NavRequest::Action`]
     pub key_action: KeyCode,
+    /// Keyboard key for [`NavRequest::SecondaryAction`]
+    pub key_secondary_action: KeyCode,
+    /// Keyboard key for [`NavRequest::TertiaryAction`]
+    pub key_tertiary_action: KeyCode,
     /// Keyboard key for [`NavRequest::Cancel`]
     pub key_cancel: KeyCode,
     /// Keyboard key for [`ScopeDirection::Next`] [`NavRequest::ScopeMove`]
@@ -72,8 +104,26 @@ pub struct InputMapping {
     pub key_previous: KeyCode,
     /// Keyboard key for [`NavRequest::Unlock`]
     pub key_free: KeyCode,
-    /// Whether mouse hover gives focus to [`Focusable`](crate::resolve::Focusable) elements.
+    /// Keyboard shortcuts requiring [`Modifiers`], and the request they send.
+    ///
+    /// When a shortcut is pressed, the single-key bindings above
+    /// for the same key are ignored. By default, `Shift+Tab` sends
+    /// a [`ScopeDirection::Previous`] [`NavRequest::ScopeMove`].
+    ///
+    /// Shortcuts are active even without [`Self::keyboard_navigation`].
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub key_combos: Vec<(Modifiers, KeyCode, NavRequest)>,
+    /// Whether mouse hover gives focus to [`Focusable`] elements.
     pub focus_follows_mouse: bool,
+    /// Whether typing text jumps focus to the [`Focusable`] with a matching
+    /// [`Name`], see [`type_ahead_input`].
+    ///
+    /// Letter keys used for movement, such as `WASD` with
+    /// [`Self::keyboard_navigation`], will also be typed.
+    pub type_ahead: bool,
+    /// Seconds without typing after which [`type_ahead_input`] starts
+    /// a new search.
+    pub type_ahead_timeout: f32,
 }
 impl Default for InputMapping {
     fn default() -> Self {
@@ -81,6 +131,9 @@ impl Default for InputMapping {
             keyboard_navigation: false,
             gamepads: vec![Gamepad { id: 0 }],
             joystick_ui_deadzone: 0.36,
+            stick_mode: StickMode::ReCenter,
+            diagonal_navigation: false,
+            stick_hysteresis: 0.0,
             move_x: GamepadAxisType::LeftStickX,
             move_y: GamepadAxisType::LeftStickY,
             left_button: GamepadButtonType::DPadLeft,
@@ -88,6 +141,8 @@ impl Default for InputMapping {
             up_button: GamepadButtonType::DPadUp,
             down_button: GamepadButtonType::DPadDown,
             action_button: GamepadButtonType::South,
+            secondary_action_button: GamepadButtonType::West,
+            tertiary_action_button: GamepadButtonType::North,
             cancel_button: GamepadButtonType::East,
             previous_button: GamepadButtonType::LeftTrigger,
             next_button: GamepadButtonType::RightTrigger,
@@ -101,12 +156,280 @@ impl Default for InputMapping {
             key_up_alt: KeyCode::Up,
             key_down_alt: KeyCode::Down,
             key_action: KeyCode::Space,
+            key_secondary_action: KeyCode::R,
+            key_tertiary_action: KeyCode::F,
             key_cancel: KeyCode::Back,
             key_next: KeyCode::E,
             key_next_alt: KeyCode::Tab,
             key_previous: KeyCode::Q,
             key_free: KeyCode::Escape,
+            key_combos: vec![(
+                Modifiers::SHIFT,
+                KeyCode::Tab,
+                NavRequest::ScopeMove(ScopeDirection::Previous),
+            )],
             focus_follows_mouse: false,
+            type_ahead: false,
+            type_ahead_timeout: 1.0,
+        }
+    }
+}
+
+impl InputMapping {
+    /// All the keys and gamepad buttons bound to each [`NavRequest`].
+    ///
+    /// Useful to list the bindings in a "Controls" menu. Keys and buttons
+    /// for [`NavRequest::Action`] are listed as `Action`, even if the default
+    /// input systems send a [`NavRequest::ActionFrom`].
+    /// [`InputMapping::key_combos`] are not included.
+    pub fn bindings(&self) -> Vec<(NavRequest, Binding)> {
+        use Binding::{AltKey, Gamepad, Key};
+        use Direction::*;
+        use NavRequest::*;
+        use ScopeDirection::{Next, Previous};
+        vec![
+            (Move(West), Key(self.key_left)),
+            (Move(East), Key(self.key_right)),
+            (Move(North), Key(self.key_up)),
+            (Move(South), Key(self.key_down)),
+            (Move(West), AltKey(self.key_left_alt)),
+            (Move(East), AltKey(self.key_right_alt)),
+            (Move(North), AltKey(self.key_up_alt)),
+            (Move(South), AltKey(self.key_down_alt)),
+            (Action, Key(self.key_action)),
+            (SecondaryAction, Key(self.key_secondary_action)),
+            (TertiaryAction, Key(self.key_tertiary_action)),
+            (Cancel, Key(self.key_cancel)),
+            (ScopeMove(Next), Key(self.key_next)),
+            (ScopeMove(Next), AltKey(self.key_next_alt)),
+            (ScopeMove(Previous), Key(self.key_previous)),
+            (Unlock, Key(self.key_free)),
+            (Move(West), Gamepad(self.left_button)),
+            (Move(East), Gamepad(self.right_button)),
+            (Move(North), Gamepad(self.up_button)),
+            (Move(South), Gamepad(self.down_button)),
+            (Action, Gamepad(self.action_button)),
+            (SecondaryAction, Gamepad(self.secondary_action_button)),
+            (TertiaryAction, Gamepad(self.tertiary_action_button)),
+            (Cancel, Gamepad(self.cancel_button)),
+            (ScopeMove(Next), Gamepad(self.next_button)),
+            (ScopeMove(Previous), Gamepad(self.previous_button)),
+            (Unlock, Gamepad(self.free_button)),
+        ]
+    }
+
+    /// Bind `request` to `binding`, replacing the previous binding
+    /// of the same kind, see [`InputMapping::bindings`].
+    ///
+    /// Returns `false` and does nothing if there is no such binding,
+    /// for example there is no [`Binding::AltKey`] for [`NavRequest::Cancel`].
+    pub fn set_binding(&mut self, request: NavRequest, binding: Binding) -> bool {
+        use Direction::*;
+        use NavRequest::*;
+        use ScopeDirection::{Next, Previous};
+        match binding {
+            Binding::Key(key) => {
+                let field = match request {
+                    Move(West) => &mut self.key_left,
+                    Move(East) => &mut self.key_right,
+                    Move(North) => &mut self.key_up,
+                    Move(South) => &mut self.key_down,
+                    Action => &mut self.key_action,
+                    SecondaryAction => &mut self.key_secondary_action,
+                    TertiaryAction => &mut self.key_tertiary_action,
+                    Cancel => &mut self.key_cancel,
+                    ScopeMove(Next) => &mut self.key_next,
+                    ScopeMove(Previous) => &mut self.key_previous,
+                    Unlock => &mut self.key_free,
+                    _ => return false,
+                };
+                *field = key;
+            }
+            Binding::AltKey(key) => {
+                let field = match request {
+                    Move(West) => &mut self.key_left_alt,
+                    Move(East) => &mut self.key_right_alt,
+                    Move(North) => &mut self.key_up_alt,
+                    Move(South) => &mut self.key_down_alt,
+                    ScopeMove(Next) => &mut self.key_next_alt,
+                    _ => return false,
+                };
+                *field = key;
+            }
+            Binding::Gamepad(button) => {
+                let field = match request {
+                    Move(West) => &mut self.left_button,
+                    Move(East) => &mut self.right_button,
+                    Move(North) => &mut self.up_button,
+                    Move(South) => &mut self.down_button,
+                    Action => &mut self.action_button,
+                    SecondaryAction => &mut self.secondary_action_button,
+                    TertiaryAction => &mut self.tertiary_action_button,
+                    Cancel => &mut self.cancel_button,
+                    ScopeMove(Next) => &mut self.next_button,
+                    ScopeMove(Previous) => &mut self.previous_button,
+                    Unlock => &mut self.free_button,
+                    _ => return false,
+                };
+                *field = button;
+            }
+        }
+        true
+    }
+}
+
+/// A key or gamepad button bound to a [`NavRequest`],
+/// see [`InputMapping::bindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    /// A keyboard key, such as [`InputMapping::key_up`].
+    Key(KeyCode),
+    /// An alternative keyboard key, such as [`InputMapping::key_up_alt`].
+    AltKey(KeyCode),
+    /// A gamepad button, such as [`InputMapping::up_button`].
+    Gamepad(GamepadButtonType),
+}
+impl From<KeyCode> for Binding {
+    fn from(key: KeyCode) -> Self {
+        Binding::Key(key)
+    }
+}
+impl From<GamepadButtonType> for Binding {
+    fn from(button: GamepadButtonType) -> Self {
+        Binding::Gamepad(button)
+    }
+}
+
+/// Modifier keys to hold for a keyboard shortcut in [`InputMapping::key_combos`].
+///
+/// Left and right modifier keys are equivalent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Modifiers {
+    /// Either shift key.
+    pub shift: bool,
+    /// Either control key.
+    pub control: bool,
+    /// Either alt key.
+    pub alt: bool,
+}
+impl Modifiers {
+    /// Only shift.
+    pub const SHIFT: Self = Self {
+        shift: true,
+        control: false,
+        alt: false,
+    };
+    /// Only control.
+    pub const CONTROL: Self = Self {
+        shift: false,
+        control: true,
+        alt: false,
+    };
+    /// Only alt.
+    pub const ALT: Self = Self {
+        shift: false,
+        control: false,
+        alt: true,
+    };
+
+    /// The modifiers currently held on `keyboard`.
+    pub fn pressed(keyboard: &Input<KeyCode>) -> Self {
+        Modifiers {
+            shift: keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            control: keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        }
+    }
+    /// Whether all modifiers of `self` are held in `pressed`.
+    fn held_in(&self, pressed: Modifiers) -> bool {
+        (!self.shift || pressed.shift)
+            && (!self.control || pressed.control)
+            && (!self.alt || pressed.alt)
+    }
+}
+
+/// How [`default_gamepad_input`] moves focus when the gamepad stick is held
+/// outside of the [deadzone].
+///
+/// [deadzone]: InputMapping::joystick_ui_deadzone
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum StickMode {
+    /// Move once, the stick must return to the deadzone before moving again.
+    #[default]
+    ReCenter,
+    /// Move once, then repeatedly while the stick is held,
+    /// like a held keyboard key.
+    Repeat {
+        /// Seconds to wait after the first move before repeating.
+        delay: f32,
+        /// Moves per second after `delay`.
+        rate: f32,
+    },
+}
+
+/// State of the gamepad stick in [`default_gamepad_input`], see [`StickMode`].
+#[derive(Default)]
+pub struct StickState {
+    held: Option<Direction>,
+    until_repeat: f32,
+}
+impl StickState {
+    /// The [`Direction`] the stick `delta` points to, see [`stick_direction`].
+    ///
+    /// Keeps the held direction while `delta` is less than `hysteresis`
+    /// radians past the edge of its sector.
+    fn direction(&self, delta: Vec2, diagonals: bool, hysteresis: f32) -> Direction {
+        use std::f32::consts::{FRAC_PI_4, FRAC_PI_8};
+
+        let direction = stick_direction(delta, diagonals);
+        match self.held {
+            Some(held) if held != direction && (diagonals || !held.is_diagonal()) => {
+                let half_sector = if diagonals { FRAC_PI_8 } else { FRAC_PI_4 };
+                let held_angle = Vec2::from_angle(stick_angle(held));
+                let from_held = held_angle.angle_between(delta).abs();
+                if from_held < half_sector + hysteresis {
+                    held
+                } else {
+                    direction
+                }
+            }
+            _ => direction,
+        }
+    }
+    /// Update the state with the stick `direction`, `None` when in the deadzone,
+    /// `delta_seconds` after the last update.
+    ///
+    /// Returns the direction to move in, if any.
+    fn update(
+        &mut self,
+        direction: Option<Direction>,
+        mode: StickMode,
+        delta_seconds: f32,
+    ) -> Option<Direction> {
+        let Some(direction) = direction else {
+            self.held = None;
+            return None;
+        };
+        match (mode, self.held) {
+            (StickMode::ReCenter, Some(_)) => None,
+            (StickMode::Repeat { delay, .. }, held) if held != Some(direction) => {
+                self.held = Some(direction);
+                self.until_repeat = delay;
+                Some(direction)
+            }
+            (StickMode::Repeat { rate, .. }, Some(_)) => {
+                self.until_repeat -= delta_seconds;
+                if self.until_repeat > 0.0 || rate <= 0.0 {
+                    return None;
+                }
+                self.until_repeat += rate.recip();
+                Some(direction)
+            }
+            (_, None) => {
+                self.held = Some(direction);
+                Some(direction)
+            }
         }
     }
 }
@@ -116,10 +439,38 @@ macro_rules! mapping {
     ($($from:expr => $to:expr),* ) => ([$( ( $from, $to ) ),*])
 }
 
+/// The [`Direction`] the gamepad stick `delta` points to, `y` pointing up.
+///
+/// With `diagonals`, each [`Direction`] covers a 45° sector,
+/// otherwise only the four cardinal directions are used.
+fn stick_direction(delta: Vec2, diagonals: bool) -> Direction {
+    if diagonals {
+        Direction::from_vec2_diagonal(delta)
+    } else {
+        Direction::from_vec2(delta)
+    }
+}
+
+/// The angle of `direction` on the gamepad stick, `y` pointing up.
+fn stick_angle(direction: Direction) -> f32 {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    use Direction::*;
+    match direction {
+        East => 0.0,
+        NorthEast => FRAC_PI_4,
+        North => FRAC_PI_2,
+        NorthWest => 3.0 * FRAC_PI_4,
+        West => PI,
+        SouthWest => -3.0 * FRAC_PI_4,
+        South => -FRAC_PI_2,
+        SouthEast => -FRAC_PI_4,
+    }
+}
+
 /// A system to send gamepad control events to the focus system
 ///
 /// Dpad and left stick for movement, `LT` and `RT` for scopped menus, `A` `B`
-/// for selection and cancel.
+/// for selection and cancel, `X` and `Y` for secondary and tertiary actions.
 ///
 /// The button mapping may be controlled through the [`InputMapping`] resource.
 /// You may however need to customize the behavior of this system (typically
@@ -131,10 +482,12 @@ pub fn default_gamepad_input(
     input_mapping: Res<InputMapping>,
     buttons: Res<Input<GamepadButton>>,
     axis: Res<Axis<GamepadAxis>>,
-    mut ui_input_status: Local<bool>,
+    time: Res<Time>,
+    mut stick: Local<StickState>,
 ) {
-    use Direction::*;
-    use NavRequest::{Action, Cancel, Move, ScopeMove, Unlock};
+    use NavRequest::{
+        ActionFrom, Cancel, Move, ScopeMove, SecondaryAction, TertiaryAction, Unlock,
+    };
 
     if has_focused.is_empty() {
         // Do not compute navigation if there is no focus to change
@@ -151,21 +504,21 @@ pub fn default_gamepad_input(
         }
 
         let delta = axis_delta!(Y, move_y) + axis_delta!(X, move_x);
-        if delta.length_squared() > input_mapping.joystick_ui_deadzone && !*ui_input_status {
-            let direction = match () {
-                () if delta.y < delta.x && delta.y < -delta.x => South,
-                () if delta.y < delta.x => East,
-                () if delta.y >= delta.x && delta.y > -delta.x => North,
-                () => West,
-            };
+        let (diagonals, hysteresis) = (
+            input_mapping.diagonal_navigation,
+            input_mapping.stick_hysteresis,
+        );
+        let direction = (delta.length_squared() > input_mapping.joystick_ui_deadzone)
+            .then(|| stick.direction(delta, diagonals, hysteresis));
+        let mode = input_mapping.stick_mode;
+        if let Some(direction) = stick.update(direction, mode, time.delta_seconds()) {
             nav_cmds.send(Move(direction));
-            *ui_input_status = true;
-        } else if delta.length_squared() <= input_mapping.joystick_ui_deadzone {
-            *ui_input_status = false;
         }
 
         let command_mapping = mapping! {
-            input_mapping.action_button => Action,
+            input_mapping.action_button => ActionFrom(InputSource::Gamepad),
+            input_mapping.secondary_action_button => SecondaryAction,
+            input_mapping.tertiary_action_button => TertiaryAction,
             input_mapping.cancel_button => Cancel,
             input_mapping.left_button => Move(Direction::West),
             input_mapping.right_button => Move(Direction::East),
@@ -189,8 +542,9 @@ pub fn default_gamepad_input(
 
 /// A system to send keyboard control events to the focus system.
 ///
-/// supports `WASD` and arrow keys for the directions, `E`, `Q` and `Tab` for
-/// scopped menus, `Backspace` and `Enter` for cancel and selection.
+/// supports `WASD` and arrow keys for the directions, `E`, `Q`, `Tab` and
+/// `Shift+Tab` for scopped menus, `Backspace` and `Enter` for cancel and selection,
+/// `R` and `F` for secondary and tertiary actions.
 ///
 /// The button mapping may be controlled through the [`InputMapping`] resource.
 /// You may however need to customize the behavior of this system (typically
@@ -221,15 +575,25 @@ pub fn default_keyboard_input(
         input_mapping.key_right_alt => Move(East)
     };
     let without_movement = mapping! {
-        input_mapping.key_action => Action,
+        input_mapping.key_action => ActionFrom(InputSource::Keyboard),
+        input_mapping.key_secondary_action => SecondaryAction,
+        input_mapping.key_tertiary_action => TertiaryAction,
         input_mapping.key_cancel => Cancel,
         input_mapping.key_next => ScopeMove(ScopeDirection::Next),
         input_mapping.key_next_alt => ScopeMove(ScopeDirection::Next),
         input_mapping.key_free => Unlock,
         input_mapping.key_previous => ScopeMove(ScopeDirection::Previous)
     };
+    let pressed = Modifiers::pressed(&keyboard);
+    let mut combo_keys = Vec::new();
+    for &(modifiers, key, request) in &input_mapping.key_combos {
+        if keyboard.just_pressed(key) && modifiers.held_in(pressed) {
+            combo_keys.push(key);
+            nav_cmds.send(request);
+        }
+    }
     let mut send_command = |&(key, request)| {
-        if keyboard.just_pressed(key) {
+        if keyboard.just_pressed(key) && !combo_keys.contains(&key) {
             nav_cmds.send(request)
         }
     };
@@ -239,8 +603,111 @@ pub fn default_keyboard_input(
     without_movement.iter().for_each(send_command);
 }
 
-/// Update [`ScreenBoundaries`] resource when the UI camera change
-/// (assuming there is a unique one).
+/// Characters typed so far by [`type_ahead_input`].
+#[derive(Default)]
+pub struct TypeAheadBuffer {
+    text: String,
+    last_input: f32,
+}
+
+/// Whether [`type_ahead_input`] is enabled and has the resources it reads.
+fn can_type_ahead(
+    input_mapping: Res<InputMapping>,
+    time: Option<Res<Time>>,
+    typed: Option<Res<Events<ReceivedCharacter>>>,
+) -> bool {
+    input_mapping.type_ahead && time.is_some() && typed.is_some()
+}
+
+/// Jump focus to the [`Focusable`] whose [`Name`] starts with typed text,
+/// like in OS file lists.
+///
+/// Only [`Focusable`]s in the same menu as the currently focused one are
+/// considered. Typing the same letter repeatedly cycles through the
+/// [`Focusable`]s starting with that letter.
+/// The search is reset after [`InputMapping::type_ahead_timeout`] seconds.
+///
+/// This does nothing unless [`InputMapping::type_ahead`] is enabled.
+/// [`DefaultNavigationSystems`] only runs it when it is, and when the
+/// [`Time`] resource and [`ReceivedCharacter`] events exist.
+#[allow(clippy::too_many_arguments)]
+pub fn type_ahead_input(
+    input_mapping: Res<InputMapping>,
+    time: Res<Time>,
+    mut typed: EventReader<ReceivedCharacter>,
+    mut buffer: Local<TypeAheadBuffer>,
+    focused: Query<Entity, With<Focused>>,
+    focusables: Query<(&Focusable, Option<&Name>)>,
+    menus: Query<(), With<MenuSetting>>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut nav_cmds: EventWriter<NavRequest>,
+) {
+    if !input_mapping.type_ahead {
+        typed.clear();
+        return;
+    }
+    let now = time.elapsed_seconds();
+    let mut changed = false;
+    for event in typed.read() {
+        // Space is the default action key, ignore it at the start of a search.
+        let starts_search =
+            buffer.text.is_empty() || now - buffer.last_input > input_mapping.type_ahead_timeout;
+        if event.char.is_control() || (starts_search && event.char.is_whitespace()) {
+            continue;
+        }
+        if starts_search {
+            buffer.text.clear();
+        }
+        buffer.text.push(event.char);
+        buffer.last_input = now;
+        changed = true;
+    }
+    let Ok(focused) = focused.get_single() else {
+        return;
+    };
+    if !changed {
+        return;
+    }
+    // The menu containing `focused`, or the root of its hierarchy.
+    let mut menu = focused;
+    while let Ok(parent) = parents.get(menu) {
+        menu = parent.get();
+        if menus.contains(menu) {
+            break;
+        }
+    }
+    // Walk the hierarchy under `menu` in order, without entering sub-menus.
+    let mut candidates = Vec::new();
+    let mut to_visit = vec![menu];
+    while let Some(entity) = to_visit.pop() {
+        match focusables.get(entity) {
+            Ok((focusable, Some(name))) if !focusable.is_blocked() => {
+                candidates.push((entity, name.as_str()));
+            }
+            Ok(_) => {}
+            Err(_) if entity != menu && menus.contains(entity) => {}
+            Err(_) => {
+                let entity_children = children.get(entity).into_iter().flatten();
+                // Reversed, so that `pop` returns children in order.
+                to_visit.extend(entity_children.rev());
+            }
+        }
+    }
+    let names: Vec<_> = candidates.iter().map(|(_, name)| *name).collect();
+    let from = candidates.iter().position(|(e, _)| *e == focused);
+    if let Some(index) = type_ahead_index(from, &buffer.text, &names) {
+        let (target, _) = candidates[index];
+        if target != focused {
+            nav_cmds.send(NavRequest::FocusOn(target));
+        }
+    }
+}
+
+/// Update [`ScreenBoundaries`] resource when the UI camera change.
+///
+/// With several cameras showing UI, add a [`NavCamera`] to the one
+/// that should drive the boundaries.
 ///
 /// See [`ScreenBoundaries`] doc for details.
 #[cfg(feature = "bevy_ui")]
@@ -248,14 +715,17 @@ pub fn default_keyboard_input(
 pub fn update_boundaries(
     mut commands: Commands,
     mut boundaries: Option<ResMut<ScreenBoundaries>>,
-    cam: Query<(&Camera, Option<&UiCameraConfig>), Or<(Changed<Camera>, Changed<UiCameraConfig>)>>,
+    mut warned: Local<bool>,
+    changed: Query<(), Or<(Changed<Camera>, Changed<UiCameraConfig>, Changed<NavCamera>)>>,
+    mut removed: RemovedComponents<NavCamera>,
+    cameras: Query<(&Camera, Option<&UiCameraConfig>, Has<NavCamera>)>,
 ) {
-    // TODO: this assumes there is only a single camera with activated UI.
-    let first_visible_ui_cam = |(cam, config): (_, Option<&UiCameraConfig>)| {
-        config.map_or(true, |c| c.show_ui).then_some(cam)
-    };
+    let removed = removed.read().count() != 0;
+    if changed.is_empty() && !removed {
+        return;
+    }
     let mut update_boundaries = || {
-        let cam = cam.iter().find_map(first_visible_ui_cam)?;
+        let cam = nav_camera(&cameras, &mut warned)?;
         let physical_size = cam.physical_viewport_size()?;
         let new_boundaries = ScreenBoundaries {
             position: Vec2::ZERO,
@@ -275,10 +745,78 @@ pub fn update_boundaries(
     update_boundaries();
 }
 
+/// A [`Focusable`] currently under a pointer.
+///
+/// This is independent from the navigation focus: a `Hovered` entity
+/// isn't necessarily [`Focused`], unless [`InputMapping::focus_follows_mouse`]
+/// is enabled. Use it to render hover differently from focus.
+///
+/// Inserted and removed by the handlers added in [`enable_click_request`].
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[cfg(feature = "pointer_focus")]
+#[derive(Component, Debug, Clone, Copy)]
+#[component(storage = "SparseSet")]
+pub struct Hovered;
+
+/// A [`Focusable`] currently pressed by a pointer.
+///
+/// Like [`Hovered`], this is independent from the navigation focus.
+/// It is removed when the pointer is released or leaves the entity.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[cfg(feature = "pointer_focus")]
+#[derive(Component, Debug, Clone, Copy)]
+#[component(storage = "SparseSet")]
+pub struct Pressed;
+
+/// Whether clicking this [`Focusable`] activates it, `true` when absent.
+///
+/// Clicking a `ClickActivates(false)` focusable still focuses it, but
+/// doesn't activate it: only keyboard and gamepad actions do. Use it for
+/// focusables that clicks should only select, such as list items
+/// activated with a separate confirm button.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[cfg(feature = "pointer_focus")]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct ClickActivates(pub bool);
+#[cfg(feature = "pointer_focus")]
+impl Default for ClickActivates {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// The [`NavRequest`]s a click on `entity`, a focusable in the given state,
+/// sends.
+#[cfg(feature = "pointer_focus")]
+pub(crate) fn click_requests(
+    entity: Entity,
+    focusable: Option<&Focusable>,
+    activates: Option<&ClickActivates>,
+) -> Vec<NavRequest> {
+    if !matches!(focusable, Some(f) if !f.is_blocked()) {
+        return Vec::new();
+    }
+    let focus = NavRequest::FocusOn(entity);
+    if activates.map_or(true, |a| a.0) {
+        vec![focus, NavRequest::ActionFrom(InputSource::Pointer)]
+    } else {
+        vec![focus]
+    }
+}
+
 #[cfg(feature = "pointer_focus")]
+#[allow(clippy::type_complexity)]
 fn send_request<E: EntityEvent>(
-    f: impl Fn(Query<&crate::resolve::Focusable>, Res<ListenerInput<E>>, EventWriter<NavRequest>)
-        + Send
+    f: impl Fn(
+            Query<(&Focusable, Option<&ClickActivates>)>,
+            Res<ListenerInput<E>>,
+            EventWriter<NavRequest>,
+            Commands,
+        ) + Send
         + Sync
         + Copy
         + 'static,
@@ -289,6 +827,11 @@ fn send_request<E: EntityEvent>(
 /// Send [`NavRequest`]s when an [`Entity`] is clicked, as defined by
 /// [`bevy_mod_picking`].
 ///
+/// This also keeps the [`Hovered`] and [`Pressed`] markers up to date.
+/// Clicking a focusable with [`ClickActivates(false)`] only focuses it.
+///
+/// [`ClickActivates(false)`]: ClickActivates
+///
 /// # `bevy_mod_picking` features
 ///
 /// `bevy-ui-navigation` inserts the [`DefaultPickingPlugins`].
@@ -313,29 +856,32 @@ pub fn enable_click_request(
     to_add: Query<Entity, (With<crate::resolve::Focusable>, Without<On<Pointer<Click>>>)>,
     mut commands: Commands,
 ) {
-    use crate::prelude::FocusState::Blocked;
-
-    let on_click = send_request::<Pointer<Click>>(|q, e, mut evs| {
+    let on_click = send_request::<Pointer<Click>>(|q, e, mut evs, _| {
+        let (focusable, activates) = q
+            .get(e.listener())
+            .map_or((None, None), |(f, a)| (Some(f), a));
         // TODO(clean): This shouldn't be the responsability of the input system.
-        if matches!(q.get(e.listener()), Ok(f) if f.state() != Blocked) {
-            evs.send(NavRequest::FocusOn(e.listener()));
-            evs.send(NavRequest::Action);
-        }
-    });
-    let on_down = send_request::<Pointer<Down>>(|_, e, mut evs| {
-        evs.send(NavRequest::FocusOn(e.listener()));
+        evs.send_batch(click_requests(e.listener(), focusable, activates));
     });
-    let on_over = send_request::<Pointer<Over>>(|_, e, mut evs| {
+    let on_down = send_request::<Pointer<Down>>(|_, e, mut evs, mut cmds| {
+        cmds.entity(e.listener()).insert(Pressed);
         evs.send(NavRequest::FocusOn(e.listener()));
     });
+    let on_up = || On::<Pointer<Up>>::listener_remove::<Pressed>();
+    let on_out = || On::<Pointer<Out>>::listener_remove::<(Hovered, Pressed)>();
     if input_mapping.focus_follows_mouse {
-        let cmd_entry = |e| (e, (on_click(), on_down(), on_over()));
+        let on_over = send_request::<Pointer<Over>>(|_, e, mut evs, mut cmds| {
+            cmds.entity(e.listener()).insert(Hovered);
+            evs.send(NavRequest::FocusOn(e.listener()));
+        });
+        let cmd_entry = |e| (e, (on_click(), on_down(), on_up(), on_over(), on_out()));
         let batch_cmd: Vec<_> = to_add.iter().map(cmd_entry).collect();
         if !batch_cmd.is_empty() {
             commands.insert_or_spawn_batch(batch_cmd);
         }
     } else {
-        let cmd_entry = |e| (e, (on_click(), on_down()));
+        let on_over = || On::<Pointer<Over>>::listener_insert(Hovered);
+        let cmd_entry = |e| (e, (on_click(), on_down(), on_up(), on_over(), on_out()));
         let batch_cmd: Vec<_> = to_add.iter().map(cmd_entry).collect();
         if !batch_cmd.is_empty() {
             commands.insert_or_spawn_batch(batch_cmd);
@@ -343,6 +889,175 @@ pub fn enable_click_request(
     };
 }
 
+/// Block or unblock all [`Focusable`]s with the `C` component,
+/// depending on `focusable`.
+///
+/// Only touches [`Focusable`]s which state needs to change,
+/// to avoid triggering change detection.
+fn set_blocked<C: Component>(
+    In(focusable): In<bool>,
+    mut focusables: Query<&mut Focusable, With<C>>,
+) {
+    for mut to_update in &mut focusables {
+        if to_update.is_blocked() == focusable {
+            if focusable {
+                to_update.unblock();
+            } else {
+                to_update.block();
+            }
+        }
+    }
+}
+
+/// Add focusables which navigability depends on the game state.
+pub trait ConditionalFocusableExt {
+    /// Block all [`Focusable`]s with the `C` component when `condition`
+    /// returns `false`, and unblock them when it returns `true`.
+    ///
+    /// `condition` is any system returning a `bool`, like bevy's run conditions.
+    /// It runs each frame in the [`FocusableCondition`] set,
+    /// before the [`NavRequestSystem`].
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_navigation::prelude::*;
+    /// #[derive(Component)]
+    /// struct ContinueButton;
+    /// #[derive(Resource)]
+    /// struct SaveFile;
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .add_conditional_focusable::<ContinueButton, _>(resource_exists::<SaveFile>());
+    /// }
+    /// ```
+    ///
+    /// As with [`Focusable::block`], [`Focused`] and active focusables
+    /// are blocked once focus moved to a sibling.
+    ///
+    /// [`FocusableCondition`]: crate::FocusableCondition
+    /// [`NavRequestSystem`]: crate::NavRequestSystem
+    fn add_conditional_focusable<C: Component, M>(
+        &mut self,
+        condition: impl IntoSystem<(), bool, M>,
+    ) -> &mut Self;
+
+    /// Block all [`Focusable`]s with the `C` component when not in `state`,
+    /// and unblock them when in `state`.
+    ///
+    /// This is [`ConditionalFocusableExt::add_conditional_focusable`]
+    /// with a [`state_exists_and_equals`] condition.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_navigation::prelude::*;
+    /// #[derive(Component)]
+    /// struct ContinueButton;
+    /// #[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    /// enum Save {
+    ///     #[default]
+    ///     None,
+    ///     Exists,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new()
+    ///         .add_state::<Save>()
+    ///         .focusable_enabled_in_state::<ContinueButton, _>(Save::Exists);
+    /// }
+    /// ```
+    fn focusable_enabled_in_state<C: Component, S: States>(&mut self, state: S) -> &mut Self {
+        self.add_conditional_focusable::<C, _>(state_exists_and_equals(state))
+    }
+}
+impl ConditionalFocusableExt for App {
+    fn add_conditional_focusable<C: Component, M>(
+        &mut self,
+        condition: impl IntoSystem<(), bool, M>,
+    ) -> &mut Self {
+        let system = condition.pipe(set_blocked::<C>);
+        self.add_systems(Update, system.in_set(crate::FocusableCondition))
+    }
+}
+
+/// Insert the [`Focusable`] created by `make` on new `C` entities
+/// without one.
+#[allow(clippy::type_complexity)]
+fn insert_focusable<C: Component>(
+    make: impl Fn(&C) -> Focusable + Send + Sync + 'static,
+) -> impl FnMut(Commands, Query<(Entity, &C), (Added<C>, Without<Focusable>)>) {
+    move |mut commands, added| {
+        let focusables: Vec<_> = added.iter().map(|(e, c)| (e, make(c))).collect();
+        if !focusables.is_empty() {
+            commands.insert_or_spawn_batch(focusables);
+        }
+    }
+}
+
+/// Automatically make entities with a given component [`Focusable`].
+pub trait AutoFocusableExt {
+    /// Insert a default [`Focusable`] on entities when the `C` component
+    /// is added to them, unless they already have one.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_navigation::prelude::*;
+    /// #[derive(Component)]
+    /// struct MyButton;
+    ///
+    /// fn main() {
+    ///     App::new().auto_focusable::<MyButton>();
+    /// }
+    /// ```
+    ///
+    /// The [`Focusable`] is inserted right before menus are built, so menus
+    /// spawned with `C` entities work on their first frame. This is in
+    /// `PreUpdate`, or the schedule set with [`GenericNavigationPlugin::in_schedule`].
+    /// Add the navigation plugin before calling this, otherwise `PreUpdate` is used.
+    ///
+    /// [`GenericNavigationPlugin::in_schedule`]: crate::GenericNavigationPlugin::in_schedule
+    fn auto_focusable<C: Component>(&mut self) -> &mut Self {
+        self.auto_focusable_with(|_: &C| Focusable::default())
+    }
+
+    /// Like [`AutoFocusableExt::auto_focusable`], but inserting
+    /// the [`Focusable`] returned by `make` for the added `C` component.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_ui_navigation::prelude::*;
+    /// #[derive(Component)]
+    /// enum MyButton {
+    ///     Back,
+    ///     Play,
+    /// }
+    ///
+    /// fn main() {
+    ///     App::new().auto_focusable_with(|button: &MyButton| match button {
+    ///         MyButton::Back => Focusable::cancel(),
+    ///         MyButton::Play => Focusable::new().prioritized(),
+    ///     });
+    /// }
+    /// ```
+    fn auto_focusable_with<C: Component>(
+        &mut self,
+        make: impl Fn(&C) -> Focusable + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+impl AutoFocusableExt for App {
+    fn auto_focusable_with<C: Component>(
+        &mut self,
+        make: impl Fn(&C) -> Focusable + Send + Sync + 'static,
+    ) -> &mut Self {
+        let settings = self.world.get_resource::<crate::resolve::NavSettings>();
+        let schedule = settings.map_or(PreUpdate.intern(), |s| s.menu_schedule);
+        // Menus need the `Focusable`s when they are built in the same schedule.
+        let system = (insert_focusable(make), apply_deferred).chain();
+        let system = system.before(crate::named::resolve_named_menus);
+        self.add_systems(schedule, system)
+    }
+}
+
 /// Default input systems for ui navigation.
 pub struct DefaultNavigationSystems;
 impl Plugin for DefaultNavigationSystems {
@@ -350,14 +1065,173 @@ impl Plugin for DefaultNavigationSystems {
         use crate::NavRequestSystem;
         app.init_resource::<InputMapping>().add_systems(
             Update,
-            (default_gamepad_input, default_keyboard_input).before(NavRequestSystem),
+            (
+                default_gamepad_input,
+                default_keyboard_input,
+                type_ahead_input.run_if(can_type_ahead),
+            )
+                .before(NavRequestSystem),
         );
 
         #[cfg(feature = "bevy_ui")]
         app.add_systems(Update, update_boundaries.before(NavRequestSystem));
 
+        #[cfg(all(feature = "pointer_focus", feature = "bevy_reflect"))]
+        app.register_type::<ClickActivates>();
         #[cfg(feature = "pointer_focus")]
         app.add_plugins(DefaultPickingPlugins)
             .add_systems(PostUpdate, enable_click_request);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_ahead_without_window() {
+        // No `WindowPlugin` nor `TimePlugin`, so no `ReceivedCharacter` events nor `Time`.
+        let mut app = App::new();
+        let input_mapping = InputMapping {
+            type_ahead: true,
+            ..default()
+        };
+        app.insert_resource(input_mapping)
+            .add_systems(Update, type_ahead_input.run_if(can_type_ahead));
+        app.update();
+    }
+
+    #[test]
+    fn test_stick_modes() {
+        use Direction::{East, North};
+        let held = [
+            Some(East),
+            Some(East),
+            Some(East),
+            Some(North),
+            None,
+            Some(East),
+        ];
+        let run = |mode| {
+            let mut state = StickState::default();
+            let moves = held.iter().map(|d| state.update(*d, mode, 0.3));
+            moves.collect::<Vec<_>>()
+        };
+        let recenter = run(StickMode::ReCenter);
+        assert_eq!(recenter, [Some(East), None, None, None, None, Some(East)]);
+
+        let repeat = run(StickMode::Repeat {
+            delay: 0.5,
+            rate: 5.0,
+        });
+        let expected = [Some(East), None, Some(East), Some(North), None, Some(East)];
+        assert_eq!(repeat, expected);
+    }
+
+    #[test]
+    fn test_bindings() {
+        use Direction::North;
+
+        let mut mapping = InputMapping::default();
+        let up = NavRequest::Move(North);
+        assert!(mapping.bindings().contains(&(up, Binding::Key(KeyCode::W))));
+
+        let rebinds = [
+            (up, KeyCode::I.into()),
+            (up, GamepadButtonType::LeftThumb.into()),
+            (NavRequest::Action, Binding::Key(KeyCode::Return)),
+        ];
+        for (request, binding) in rebinds {
+            assert!(mapping.set_binding(request, binding));
+        }
+        assert_eq!(mapping.key_up, KeyCode::I);
+        let bindings = mapping.bindings();
+        assert!(rebinds.iter().all(|rebind| bindings.contains(rebind)));
+        assert!(!bindings.contains(&(up, Binding::Key(KeyCode::W))));
+        // Each binding is still listed once.
+        assert_eq!(bindings.len(), InputMapping::default().bindings().len());
+
+        // Rebinding every binding to itself changes nothing.
+        let mut copy = InputMapping::default();
+        for (request, binding) in &bindings {
+            assert!(copy.set_binding(*request, *binding));
+        }
+        assert_eq!(copy.bindings(), bindings);
+
+        let no_alt = Binding::AltKey(KeyCode::Escape);
+        assert!(!mapping.set_binding(NavRequest::Cancel, no_alt));
+        assert!(!mapping.set_binding(NavRequest::Refocus, Binding::Key(KeyCode::Escape)));
+    }
+
+    #[test]
+    fn test_key_combos() {
+        use bevy::ecs::system::RunSystemOnce;
+        use ScopeDirection::{Next, Previous};
+
+        let mut world = World::new();
+        world.init_resource::<Events<NavRequest>>();
+        world.init_resource::<InputMapping>();
+        world.spawn(Focused);
+        let mut run = |keys: &[KeyCode]| {
+            let mut keyboard = Input::<KeyCode>::default();
+            keys.iter().for_each(|key| keyboard.press(*key));
+            world.insert_resource(keyboard);
+            world.run_system_once(default_keyboard_input);
+            let mut events = world.resource_mut::<Events<NavRequest>>();
+            events.drain().collect::<Vec<_>>()
+        };
+        assert_eq!(run(&[KeyCode::Tab]), [NavRequest::ScopeMove(Next)]);
+        let shift_tab = [KeyCode::ShiftRight, KeyCode::Tab];
+        assert_eq!(run(&shift_tab), [NavRequest::ScopeMove(Previous)]);
+        // Extra modifiers still trigger the shortcut.
+        let shortcut = [KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::Tab];
+        assert_eq!(run(&shortcut), [NavRequest::ScopeMove(Previous)]);
+        assert_eq!(run(&[KeyCode::R]), [NavRequest::SecondaryAction]);
+        assert_eq!(run(&[KeyCode::F]), [NavRequest::TertiaryAction]);
+    }
+
+    #[test]
+    fn test_stick_direction() {
+        use Direction::*;
+        let sectors = [
+            (East, Vec2::new(1.0, 0.2)),
+            (NorthEast, Vec2::new(0.7, 0.8)),
+            (North, Vec2::new(-0.2, 1.0)),
+            (NorthWest, Vec2::new(-0.8, 0.7)),
+            (West, Vec2::new(-1.0, -0.2)),
+            (SouthWest, Vec2::new(-0.7, -0.8)),
+            (South, Vec2::new(0.2, -1.0)),
+            (SouthEast, Vec2::new(0.8, -0.7)),
+        ];
+        for (expected, delta) in sectors {
+            assert_eq!(stick_direction(delta, true), expected, "{delta}");
+        }
+        assert_eq!(stick_direction(Vec2::new(0.7, 0.8), false), North);
+        assert_eq!(stick_direction(Vec2::new(0.8, -0.7), false), East);
+        assert_eq!(stick_direction(Vec2::new(-0.7, -0.8), false), South);
+    }
+
+    #[test]
+    fn test_stick_hysteresis() {
+        use Direction::{East, North};
+        let mode = StickMode::Repeat {
+            delay: 0.5,
+            rate: 5.0,
+        };
+        // Wobbling around the boundary between `East` and `North`.
+        let wobble = [40.0_f32, 50.0, 42.0, 48.0, 60.0];
+        let run = |hysteresis| {
+            let mut state = StickState::default();
+            let moves = wobble.iter().map(|degrees| {
+                let delta = Vec2::from_angle(degrees.to_radians());
+                let direction = state.direction(delta, false, hysteresis);
+                state.update(Some(direction), mode, 0.1)
+            });
+            moves.collect::<Vec<_>>()
+        };
+        let jittery = [Some(East), Some(North), Some(East), Some(North), None];
+        assert_eq!(run(0.0), jittery);
+        let steady = [Some(East), None, None, None, Some(North)];
+        assert_eq!(run(10.0_f32.to_radians()), steady);
+    }
+}
@@ -0,0 +1,266 @@
+//! Check a navigation tree for the mistakes the navigation system
+//! can't recover from.
+use bevy::core::Name;
+use bevy::ecs::prelude::{Entity, Query, With, Without, World};
+use bevy::ecs::system::SystemState;
+use bevy::hierarchy::{HierarchyQueryExt, Parent};
+use bevy::utils::HashSet;
+
+use crate::menu::{MenuBuilder, MenuSetting};
+use crate::resolve::{ChildQueries, Focusable, NavLinks, TreeMenu};
+
+/// A problem in the navigation tree, found by [`validate_nav_tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavTreeIssue {
+    /// Following the parent [`Focusable`] of each menu leads back to the first one.
+    ///
+    /// Contains the menus of the cycle. The navigation system panics
+    /// when it encounters such a cycle.
+    Cycle(Vec<Entity>),
+    /// A menu without any non-blocked [`Focusable`].
+    ///
+    /// The navigation system panics when building such a menu.
+    EmptyMenu(Entity),
+    /// A [`MenuBuilder::NamedParent`] without any [`Focusable`] of that [`Name`].
+    UnresolvedNamedParent {
+        /// The menu with the `NamedParent`.
+        menu: Entity,
+        /// The name no [`Focusable`] has.
+        name: Name,
+    },
+    /// A [`NavLinks`] target that isn't a [`Focusable`] in the same menu.
+    InvalidLink {
+        /// The [`Focusable`] with the [`NavLinks`].
+        focusable: Entity,
+        /// The invalid target.
+        target: Entity,
+    },
+    /// A menu parent that isn't a [`Focusable`], for example
+    /// a despawned entity in [`MenuBuilder::EntityParent`].
+    InvalidParent {
+        /// The menu with the invalid parent.
+        menu: Entity,
+        /// The parent that isn't a [`Focusable`].
+        parent: Entity,
+    },
+}
+
+type MenuQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static TreeMenu>,
+        Option<&'static MenuBuilder>,
+    ),
+    With<MenuSetting>,
+>;
+type FocusableQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, Option<&'static Name>, Option<&'static NavLinks>),
+    (With<Focusable>, Without<TreeMenu>),
+>;
+
+/// Check all menus and [`Focusable`]s of `world` for problems.
+///
+/// Returns an empty `Vec` if the navigation tree is valid.
+/// Useful to check your UI scenes in tests, before shipping them.
+///
+/// This works both before and after the navigation systems built the menus.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_ui_navigation::prelude::*;
+/// use bevy_ui_navigation::{validate_nav_tree, NavTreeIssue};
+///
+/// let mut world = World::new();
+/// let menu = world.spawn((MenuSetting::new(), MenuBuilder::Root)).id();
+/// assert_eq!(validate_nav_tree(&mut world), [NavTreeIssue::EmptyMenu(menu)]);
+/// ```
+pub fn validate_nav_tree(world: &mut World) -> Vec<NavTreeIssue> {
+    let mut state =
+        SystemState::<(ChildQueries, Query<&Parent>, MenuQuery, FocusableQuery)>::new(world);
+    let (children, parents, menus, focusables) = state.get(world);
+
+    let mut issues = Vec::new();
+
+    // The menu `entity` is in, if any.
+    let menu_of = |entity: Entity| {
+        if let Some(menu) = children.member_of(entity) {
+            return Some(menu);
+        }
+        parents
+            .iter_ancestors(entity)
+            .find(|ancestor| menus.contains(*ancestor))
+    };
+    // The focusable leading to `menu`, if any.
+    let mut parents_of = Vec::new();
+    for (menu, tree, builder) in &menus {
+        let parent = match (tree, builder) {
+            (Some(tree), _) => tree.focus_parent,
+            (None, Some(MenuBuilder::NamedParent(name))) => {
+                let named = focusables.iter().find(|(_, n, _)| *n == Some(name));
+                if named.is_none() {
+                    let name = name.clone();
+                    issues.push(NavTreeIssue::UnresolvedNamedParent { menu, name });
+                }
+                named.map(|(e, ..)| e)
+            }
+            (None, Some(MenuBuilder::EntityParent(parent))) => Some(*parent),
+            (None, Some(MenuBuilder::Root) | None) => None,
+        };
+        if let Some(parent) = parent.filter(|p| !focusables.contains(*p)) {
+            issues.push(NavTreeIssue::InvalidParent { menu, parent });
+        }
+        if children.focusables_of(menu).is_empty() {
+            issues.push(NavTreeIssue::EmptyMenu(menu));
+        }
+        parents_of.push((menu, parent));
+    }
+    let parent_of = |menu: Entity| {
+        let parent = parents_of.iter().find(|(m, _)| *m == menu)?.1?;
+        menu_of(parent)
+    };
+    let mut checked = HashSet::new();
+    for &(start, _) in &parents_of {
+        let mut path = Vec::new();
+        let mut current = Some(start);
+        while let Some(menu) = current.filter(|m| !checked.contains(m)) {
+            if let Some(cycle_start) = path.iter().position(|m| *m == menu) {
+                issues.push(NavTreeIssue::Cycle(path[cycle_start..].to_vec()));
+                break;
+            }
+            path.push(menu);
+            current = parent_of(menu);
+        }
+        checked.extend(path);
+    }
+    for (focusable, _, links) in &focusables {
+        let menu = menu_of(focusable);
+        let invalid = |t: &Entity| !focusables.contains(*t) || menu_of(*t) != menu;
+        let targets = links.into_iter().flat_map(NavLinks::targets);
+        for target in targets.filter(invalid) {
+            issues.push(NavTreeIssue::InvalidLink { focusable, target });
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::BuildWorldChildren;
+
+    use super::*;
+    use crate::test::entity_named;
+
+    fn spawn_menu(world: &mut World, builder: MenuBuilder, children: &[&str]) -> Entity {
+        let menu = world.spawn((MenuSetting::new(), builder)).id();
+        for child in children {
+            let child = world
+                .spawn((Focusable::new(), Name::new(child.to_string())))
+                .id();
+            world.entity_mut(menu).add_child(child);
+        }
+        menu
+    }
+
+    #[test]
+    fn valid_tree() {
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A", "B"]);
+        spawn_menu(&mut world, MenuBuilder::from_named("B"), &["B1"]);
+        assert_eq!(validate_nav_tree(&mut world), []);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A"]);
+        let left = spawn_menu(&mut world, MenuBuilder::from_named("R1"), &["L1"]);
+        let right = spawn_menu(&mut world, MenuBuilder::from_named("L1"), &["R1"]);
+        let issues = validate_nav_tree(&mut world);
+        assert!(matches!(
+            &issues[..],
+            [NavTreeIssue::Cycle(cycle)] if cycle.len() == 2
+                && cycle.contains(&left)
+                && cycle.contains(&right)
+        ));
+    }
+
+    #[test]
+    fn empty_menu() {
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A"]);
+        let empty = spawn_menu(&mut world, MenuBuilder::from_named("A"), &[]);
+        let blocked = world.spawn(Focusable::new().blocked()).id();
+        let all_blocked = spawn_menu(&mut world, MenuBuilder::from_named("A"), &[]);
+        world.entity_mut(all_blocked).add_child(blocked);
+        let issues = validate_nav_tree(&mut world);
+        assert_eq!(
+            issues,
+            [
+                NavTreeIssue::EmptyMenu(empty),
+                NavTreeIssue::EmptyMenu(all_blocked)
+            ]
+        );
+    }
+
+    #[test]
+    fn focusables_outside_menus() {
+        // They form an implicit root menu.
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A"]);
+        world.spawn(Focusable::new());
+        assert_eq!(validate_nav_tree(&mut world), []);
+    }
+
+    #[test]
+    fn invalid_link() {
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A", "B"]);
+        spawn_menu(&mut world, MenuBuilder::from_named("B"), &["B1"]);
+        let [a, b, b1] = ["A", "B", "B1"].map(|name| entity_named(&mut world, name));
+        world
+            .entity_mut(a)
+            .insert(NavLinks::new().east(b).south(b1));
+        let issues = validate_nav_tree(&mut world);
+        let invalid = NavTreeIssue::InvalidLink {
+            focusable: a,
+            target: b1,
+        };
+        assert_eq!(issues, [invalid]);
+    }
+
+    #[test]
+    fn unresolved_and_invalid_parents() {
+        let mut world = World::new();
+        spawn_menu(&mut world, MenuBuilder::Root, &["A"]);
+        let unresolved = spawn_menu(&mut world, MenuBuilder::from_named("Missing"), &["B"]);
+        let despawned = world.spawn_empty().id();
+        world.despawn(despawned);
+        let invalid = spawn_menu(&mut world, MenuBuilder::EntityParent(despawned), &["C"]);
+        let issues = validate_nav_tree(&mut world);
+        let name = Name::new("Missing");
+        assert_eq!(
+            issues,
+            [
+                NavTreeIssue::UnresolvedNamedParent {
+                    menu: unresolved,
+                    name
+                },
+                NavTreeIssue::InvalidParent {
+                    menu: invalid,
+                    parent: despawned
+                },
+            ]
+        );
+        // Named parents are resolved by name even before the navigation systems run.
+        let a = entity_named(&mut world, "A");
+        world.entity_mut(a).insert(Name::new("Missing"));
+        let issues = validate_nav_tree(&mut world);
+        assert_eq!(issues.len(), 1);
+    }
+}